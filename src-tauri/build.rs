@@ -0,0 +1,37 @@
+// Captures build-time provenance for `version::build_version()` (chunk199-3):
+// the git commit hash, an ISO build date, and a release channel. Each is
+// exposed to the crate via `env!(...)` so a packaged build (no `.git`, no
+// network) still compiles — it just falls back to "unknown"/"dev".
+
+use std::process::Command;
+
+fn main() {
+    let commit = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .and_then(|o| String::from_utf8(o.stdout).ok())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| "unknown".to_string());
+
+    let build_date = Command::new("date")
+        .args(["-u", "+%Y-%m-%dT%H:%M:%SZ"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .and_then(|o| String::from_utf8(o.stdout).ok())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| "unknown".to_string());
+
+    let channel = std::env::var("DAILYOS_RELEASE_CHANNEL").unwrap_or_else(|_| "dev".to_string());
+
+    println!("cargo:rustc-env=DAILYOS_GIT_COMMIT={}", commit);
+    println!("cargo:rustc-env=DAILYOS_BUILD_DATE={}", build_date);
+    println!("cargo:rustc-env=DAILYOS_RELEASE_CHANNEL={}", channel);
+
+    println!("cargo:rerun-if-env-changed=DAILYOS_RELEASE_CHANNEL");
+    println!("cargo:rerun-if-changed=.git/HEAD");
+}
@@ -581,6 +581,11 @@ fn build_entity_result(
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
+    if std::env::args().any(|arg| arg == "--version") {
+        println!("dailyos-mcp {}", dailyos_lib::version::build_version());
+        return Ok(());
+    }
+
     let config =
         load_config().map_err(|e| anyhow::anyhow!("Failed to load DailyOS config: {e}"))?;
 
@@ -290,16 +290,34 @@ pub async fn run_capture_loop(state: Arc<AppState>, app_handle: AppHandle) {
                             // Open own DB connection to avoid holding state.db Mutex
                             // during PTY subprocess (which can run for minutes).
                             let own_db = crate::db::ActionDb::open().ok();
-                            let db_ref = own_db.as_ref();
-
-                            let result = crate::processor::transcript::process_transcript(
-                                Path::new(ws),
-                                &file_path.display().to_string(),
-                                &prompt.meeting,
-                                db_ref,
-                                &profile,
-                                Some(&ai_config),
-                            );
+
+                            // `process_transcript` runs synchronously and its GitHub
+                            // issue-sync step does its own `handle.block_on` — run it
+                            // off this worker thread via `spawn_blocking` rather than
+                            // inline, or a `block_on` nested inside one (this task is
+                            // itself spawned onto a Tokio worker) panics.
+                            let ws_path = ws.clone();
+                            let file_path_str = file_path.display().to_string();
+                            let meeting = prompt.meeting.clone();
+                            let result = tokio::task::spawn_blocking(move || {
+                                crate::processor::transcript::process_transcript(
+                                    Path::new(&ws_path),
+                                    &file_path_str,
+                                    &meeting,
+                                    own_db.as_ref(),
+                                    &profile,
+                                    Some(&ai_config),
+                                )
+                            })
+                            .await
+                            .unwrap_or_else(|e| crate::types::TranscriptResult {
+                                status: "error".to_string(),
+                                message: Some(format!(
+                                    "Transcript processing task panicked: {}",
+                                    e
+                                )),
+                                ..crate::types::TranscriptResult::default()
+                            });
 
                             if result.status == "success" {
                                 // Record transcript
@@ -12,18 +12,20 @@ use tauri::{Emitter, Manager, State};
 use crate::executor::request_workflow_execution;
 use crate::hygiene::{build_intelligence_hygiene_status, HygieneStatusView};
 use crate::json_loader::{
-    check_data_freshness, load_actions_json, load_directive, load_emails_json,
-    load_emails_json_with_sync, load_prep_json, load_schedule_json, DataFreshness,
+    check_data_freshness, load_actions_json, load_actions_json_filtered, load_directive,
+    load_emails_json, load_emails_json_filtered, load_emails_json_with_sync, load_prep_json,
+    load_schedule_json, load_schedule_json_filtered, DataFreshness, Predicate,
 };
 use crate::parser::{count_inbox, list_inbox_files};
 use crate::scheduler::get_next_run_time as scheduler_get_next_run_time;
 use crate::state::{reload_config, AppState, DbTryRead};
 use crate::types::{
     Action, CalendarEvent, CapturedOutcome, Config, DailyFocus, DashboardData, DayOverview,
-    DayStats, EmailBriefingData, EmailBriefingStats, EmailSignal, EmailSyncStatus, EnrichedEmail,
-    EntityEmailThread, ExecutionRecord, FullMeetingPrep, GoogleAuthStatus, InboxFile,
-    LiveProactiveSuggestion, Meeting, MeetingIntelligence, MeetingType, OverlayStatus,
-    PostMeetingCaptureConfig, Priority, SourceReference, WeekOverview, WorkflowId, WorkflowStatus,
+    DayStats, Email, EmailBriefingData, EmailBriefingStats, EmailSignal, EmailSyncStatus,
+    EnrichedEmail, EntityEmailThread, ExecutionRecord, FullMeetingPrep, GoogleAuthStatus,
+    InboxFile, LiveProactiveSuggestion, Meeting, MeetingIntelligence, MeetingType, OverlayStatus,
+    PostMeetingCaptureConfig, Priority, RetentionSettings, SourceReference, WeekOverview,
+    WorkflowId, WorkflowStatus,
 };
 use crate::SchedulerSender;
 
@@ -7842,6 +7844,78 @@ pub async fn rebuild_database(
     )
 }
 
+// ── Transcript Retention & Archival ──────────────────────────────────
+
+#[tauri::command]
+pub async fn prune_transcripts(
+    state: tauri::State<'_, Arc<AppState>>,
+    dry_run: bool,
+) -> Result<crate::retention::PruneReport, String> {
+    let (workspace_path, retention) = {
+        let guard = state.config.read().map_err(|_| "Lock poisoned")?;
+        let config = guard.as_ref().ok_or("Config not loaded")?;
+        (config.workspace_path.clone(), config.retention.clone())
+    };
+
+    let config = crate::retention::RetentionConfig::from(&retention);
+    Ok(crate::retention::prune_transcripts(
+        std::path::Path::new(&workspace_path),
+        &config,
+        dry_run,
+    ))
+}
+
+/// Read the current transcript retention policy.
+#[tauri::command]
+pub fn get_retention_settings(state: State<Arc<AppState>>) -> Result<RetentionSettings, String> {
+    let guard = state.config.read().map_err(|_| "Lock poisoned")?;
+    let config = guard.as_ref().ok_or("Config not loaded")?;
+    Ok(config.retention.clone())
+}
+
+/// Set the transcript retention policy (chunk199-2 follow-up): default
+/// expiration in days for `_archive`/account transcripts, per-account
+/// overrides, and whether pruning compresses in place or moves files to
+/// cold storage.
+#[tauri::command]
+pub fn set_retention_settings(
+    archive_retention_days: Option<i64>,
+    account_retention_days: Option<i64>,
+    account_overrides: Option<std::collections::HashMap<String, i64>>,
+    cold_storage_dir: Option<String>,
+    state: State<Arc<AppState>>,
+) -> Result<Config, String> {
+    if let Some(v) = archive_retention_days {
+        if v < 0 {
+            return Err("archive_retention_days must be non-negative".to_string());
+        }
+    }
+    if let Some(v) = account_retention_days {
+        if v < 0 {
+            return Err("account_retention_days must be non-negative".to_string());
+        }
+    }
+    if let Some(ref overrides) = account_overrides {
+        if overrides.values().any(|&v| v < 0) {
+            return Err("account_overrides values must be non-negative".to_string());
+        }
+    }
+
+    crate::state::create_or_update_config(&state, |config| {
+        if let Some(v) = archive_retention_days {
+            config.retention.archive_retention_days = v;
+        }
+        if let Some(v) = account_retention_days {
+            config.retention.account_retention_days = v;
+        }
+        if let Some(overrides) = account_overrides {
+            config.retention.account_overrides = overrides;
+        }
+        // An empty string clears cold storage (falls back to Compress mode).
+        config.retention.cold_storage_dir = cold_storage_dir.filter(|d| !d.is_empty());
+    })
+}
+
 /// Helper: create a default AccountJson from a DbAccount.
 fn default_account_json(account: &crate::db::DbAccount) -> crate::accounts::AccountJson {
     crate::accounts::AccountJson {
@@ -11248,3 +11322,223 @@ fn find_prior_meeting(
     stmt.query_row(param_refs.as_slice(), |row| row.get::<_, String>(0))
         .ok()
 }
+
+// =============================================================================
+// Saved Views (predicate-filtered day data)
+// =============================================================================
+
+/// A single day's meetings/actions/emails, each filtered down to the entries that
+/// match a saved [`Predicate`] view.
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FilteredDayData {
+    pub meetings: Vec<Meeting>,
+    pub actions: Vec<Action>,
+    pub emails: Vec<Email>,
+}
+
+/// Load today's meetings, actions, and emails, dropping anything that doesn't match
+/// `predicate` — the same [`Predicate`] tree is applied to all three collections, so a
+/// saved view like "this account only" filters the whole day at once.
+#[tauri::command]
+pub fn get_day_filtered(
+    predicate: Predicate,
+    state: State<Arc<AppState>>,
+) -> Result<FilteredDayData, String> {
+    let config = state
+        .config
+        .read()
+        .map_err(|_| "Lock poisoned")?
+        .clone()
+        .ok_or("No configuration loaded")?;
+    let today_dir = Path::new(&config.workspace_path).join("_today");
+
+    let (_, meetings) = load_schedule_json_filtered(&today_dir, &predicate)?;
+    let actions = load_actions_json_filtered(&today_dir, &predicate)?;
+    let emails = load_emails_json_filtered(&today_dir, &predicate)?;
+
+    Ok(FilteredDayData {
+        meetings,
+        actions,
+        emails,
+    })
+}
+
+// =============================================================================
+// Snapshots (_today/data capture & restore)
+// =============================================================================
+
+/// Capture every artifact under `_today/data` into a new versioned snapshot. Returns
+/// the snapshot directory name (its timestamp-derived id), so the caller can pass it
+/// straight to [`restore_day_snapshot`].
+#[tauri::command]
+pub fn capture_day_snapshot(state: State<Arc<AppState>>) -> Result<String, String> {
+    let config = state
+        .config
+        .read()
+        .map_err(|_| "Lock poisoned")?
+        .clone()
+        .ok_or("No configuration loaded")?;
+    let today_dir = Path::new(&config.workspace_path).join("_today");
+
+    let captured_at = chrono::Utc::now().to_rfc3339();
+    let snapshot_dir = crate::snapshot::capture_snapshot(
+        &today_dir,
+        &captured_at,
+        Some(config.profile.clone()),
+    )?;
+
+    Ok(snapshot_dir
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or_default()
+        .to_string())
+}
+
+/// Restore a previously captured snapshot (by the name returned from
+/// [`capture_day_snapshot`]) back into `_today/data`, verifying every file's checksum
+/// against its manifest first. Returns the number of files restored.
+#[tauri::command]
+pub fn restore_day_snapshot(
+    snapshot_name: String,
+    state: State<Arc<AppState>>,
+) -> Result<usize, String> {
+    let config = state
+        .config
+        .read()
+        .map_err(|_| "Lock poisoned")?
+        .clone()
+        .ok_or("No configuration loaded")?;
+    let today_dir = Path::new(&config.workspace_path).join("_today");
+    let snapshot_dir = today_dir.join("snapshots").join(&snapshot_name);
+
+    crate::snapshot::restore_snapshot(&snapshot_dir, &today_dir)
+}
+
+// =============================================================================
+// Delivery Spool (durable outbound briefing delivery)
+// =============================================================================
+
+/// Queue a rendered briefing for delivery. Returns the spooled job id.
+#[tauri::command]
+pub fn enqueue_briefing_delivery(
+    channel: String,
+    payload: String,
+    state: State<Arc<AppState>>,
+) -> Result<String, String> {
+    let config = state
+        .config
+        .read()
+        .map_err(|_| "Lock poisoned")?
+        .clone()
+        .ok_or("No configuration loaded")?;
+    let today_dir = Path::new(&config.workspace_path).join("_today");
+
+    let now = chrono::Utc::now().timestamp();
+    crate::delivery_spool::enqueue_delivery(&today_dir, &channel, &payload, now)
+}
+
+/// Attempt delivery of every due job in the spool, emitting each as a
+/// `briefing-delivery` event to the frontend. A job that the frontend isn't listening
+/// for still "fails" here (emit returns an error when there are no subscribers isn't
+/// distinguishable from a dropped message), so it's retried with backoff like any other
+/// delivery failure until `MAX_ATTEMPTS` gives up. Returns the number of jobs attempted.
+#[tauri::command]
+pub fn process_pending_deliveries(
+    state: State<Arc<AppState>>,
+    app_handle: tauri::AppHandle,
+) -> Result<usize, String> {
+    let config = state
+        .config
+        .read()
+        .map_err(|_| "Lock poisoned")?
+        .clone()
+        .ok_or("No configuration loaded")?;
+    let today_dir = Path::new(&config.workspace_path).join("_today");
+
+    let now = chrono::Utc::now().timestamp();
+    crate::delivery_spool::process_spool(&today_dir, now, |job| {
+        app_handle
+            .emit("briefing-delivery", job)
+            .map_err(|e| (None, e.to_string()))
+    })
+}
+
+/// Current status of every queued/delivered/failed briefing delivery, oldest first.
+#[tauri::command]
+pub fn get_delivery_status(
+    state: State<Arc<AppState>>,
+) -> Result<Vec<crate::delivery_spool::DeliveryJob>, String> {
+    let config = state
+        .config
+        .read()
+        .map_err(|_| "Lock poisoned")?
+        .clone()
+        .ok_or("No configuration loaded")?;
+    let today_dir = Path::new(&config.workspace_path).join("_today");
+
+    crate::delivery_spool::delivery_status(&today_dir)
+}
+
+// =============================================================================
+// Local Edit Spool (user edits that survive regeneration)
+// =============================================================================
+
+/// Today's meetings/actions/preps with spooled local edits already replayed on top.
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DayWithEdits {
+    pub meetings: Vec<Meeting>,
+    pub actions: Vec<Action>,
+    pub preps: Vec<FullMeetingPrep>,
+}
+
+/// Load today's meetings, actions, and preps with any spooled local edits
+/// (`prep_reviewed`, `user_agenda`, `user_notes`, action `status`, ...) replayed on
+/// top, so UI-owned state survives the generator's last regeneration instead of the
+/// plain `load_schedule_json`/`load_actions_json` path silently clobbering it.
+#[tauri::command]
+pub fn get_day_with_edits(state: State<Arc<AppState>>) -> Result<DayWithEdits, String> {
+    let config = state
+        .config
+        .read()
+        .map_err(|_| "Lock poisoned")?
+        .clone()
+        .ok_or("No configuration loaded")?;
+    let today_dir = Path::new(&config.workspace_path).join("_today");
+
+    let (meetings, actions, preps) = crate::edit_spool::load_day_with_edits(&today_dir)?;
+    Ok(DayWithEdits {
+        meetings,
+        actions,
+        preps,
+    })
+}
+
+/// Record a local edit to a field on a meeting, action, or prep, spooled to disk until
+/// it's folded back into the next [`get_day_with_edits`] load.
+#[tauri::command]
+pub fn record_field_edit(
+    entity_kind: crate::edit_spool::EntityKind,
+    id: String,
+    field: String,
+    value: serde_json::Value,
+    state: State<Arc<AppState>>,
+) -> Result<(), String> {
+    let config = state
+        .config
+        .read()
+        .map_err(|_| "Lock poisoned")?
+        .clone()
+        .ok_or("No configuration loaded")?;
+    let today_dir = Path::new(&config.workspace_path).join("_today");
+
+    let op = crate::edit_spool::EditOp {
+        entity_kind,
+        id,
+        field,
+        value,
+        ts: chrono::Utc::now().timestamp_millis(),
+    };
+    crate::edit_spool::record_edit(&today_dir, &op)
+}
@@ -0,0 +1,235 @@
+//! Persistent delivery spool with retry for outbound briefing delivery (I197)
+//!
+//! `DirectiveEmailSyncError` already models a failed *upstream* fetch, but outbound
+//! delivery of rendered briefings (the delivery functions that consume `Directive`)
+//! has no durability of its own — a transient failure just loses the output. This
+//! spool writes each pending delivery to disk as a serialized job, attempts delivery
+//! with exponential backoff, and records a DSN-style status per attempt (stage, code,
+//! message, retry count, next-retry time) so a briefing that fails to send survives a
+//! crash and is retried rather than silently dropped.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// Give up after this many failed attempts and mark the job `Failed`.
+const MAX_ATTEMPTS: u32 = 5;
+const BASE_BACKOFF_SECS: i64 = 30;
+
+/// DSN-style delivery stage for a spooled job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryStage {
+    Queued,
+    Sending,
+    Delivered,
+    Failed,
+}
+
+/// Status record for a spooled delivery, mirroring the shape of
+/// `DirectiveEmailSyncError` (stage/code/message) plus retry bookkeeping.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryStatus {
+    pub stage: DeliveryStage,
+    pub code: Option<String>,
+    pub message: Option<String>,
+    pub retry_count: u32,
+    /// Unix seconds; `None` once the job is `Delivered` or `Failed`.
+    pub next_retry_at: Option<i64>,
+}
+
+/// A single pending outbound delivery.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryJob {
+    pub id: String,
+    /// Delivery channel, e.g. "email", "slack".
+    pub channel: String,
+    /// Rendered briefing body to deliver.
+    pub payload: String,
+    pub created_at: i64,
+    pub status: DeliveryStatus,
+}
+
+fn spool_dir(today_dir: &Path) -> PathBuf {
+    today_dir.join("data").join("delivery-spool")
+}
+
+fn job_path(today_dir: &Path, id: &str) -> PathBuf {
+    spool_dir(today_dir).join(format!("{}.json", id))
+}
+
+fn write_job(today_dir: &Path, job: &DeliveryJob) -> Result<(), String> {
+    let content = serde_json::to_vec_pretty(job)
+        .map_err(|e| format!("Failed to serialize delivery job: {}", e))?;
+    crate::util::atomic_write(&job_path(today_dir, &job.id), &content)
+        .map_err(|e| format!("Failed to write delivery job: {}", e))
+}
+
+fn read_jobs(today_dir: &Path) -> Result<Vec<DeliveryJob>, String> {
+    let dir = spool_dir(today_dir);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut jobs = Vec::new();
+    for entry in
+        std::fs::read_dir(&dir).map_err(|e| format!("Failed to read delivery spool dir: {}", e))?
+    {
+        let entry = entry.map_err(|e| format!("Failed to read spool entry: {}", e))?;
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        let job: DeliveryJob = serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?;
+        jobs.push(job);
+    }
+    jobs.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+    Ok(jobs)
+}
+
+fn backoff_secs(retry_count: u32) -> i64 {
+    BASE_BACKOFF_SECS * 2i64.pow(retry_count.min(6))
+}
+
+/// Enqueue a briefing for delivery. Returns the job id.
+pub fn enqueue_delivery(
+    today_dir: &Path,
+    channel: &str,
+    payload: &str,
+    now: i64,
+) -> Result<String, String> {
+    let dir = spool_dir(today_dir);
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create delivery spool dir: {}", e))?;
+
+    let job = DeliveryJob {
+        id: uuid::Uuid::new_v4().to_string(),
+        channel: channel.to_string(),
+        payload: payload.to_string(),
+        created_at: now,
+        status: DeliveryStatus {
+            stage: DeliveryStage::Queued,
+            code: None,
+            message: None,
+            retry_count: 0,
+            next_retry_at: Some(now),
+        },
+    };
+    write_job(today_dir, &job)?;
+    Ok(job.id)
+}
+
+/// Attempt delivery of every job that is due (queued, with `next_retry_at <= now`),
+/// via `send`. On success a job is marked `Delivered`; on failure its retry count is
+/// bumped and `next_retry_at` pushed out by exponential backoff, until `MAX_ATTEMPTS`
+/// is reached and the job is marked `Failed`. Returns the number of jobs attempted.
+pub fn process_spool<F>(today_dir: &Path, now: i64, mut send: F) -> Result<usize, String>
+where
+    F: FnMut(&DeliveryJob) -> Result<(), (Option<String>, String)>,
+{
+    let mut processed = 0;
+    for mut job in read_jobs(today_dir)? {
+        if matches!(job.status.stage, DeliveryStage::Delivered | DeliveryStage::Failed) {
+            continue;
+        }
+        if job.status.next_retry_at.is_some_and(|next| next > now) {
+            continue;
+        }
+
+        job.status.stage = DeliveryStage::Sending;
+        match send(&job) {
+            Ok(()) => {
+                job.status.stage = DeliveryStage::Delivered;
+                job.status.code = None;
+                job.status.message = None;
+                job.status.next_retry_at = None;
+            }
+            Err((code, message)) => {
+                job.status.retry_count += 1;
+                job.status.code = code;
+                job.status.message = Some(message);
+                if job.status.retry_count >= MAX_ATTEMPTS {
+                    job.status.stage = DeliveryStage::Failed;
+                    job.status.next_retry_at = None;
+                } else {
+                    job.status.stage = DeliveryStage::Queued;
+                    job.status.next_retry_at = Some(now + backoff_secs(job.status.retry_count));
+                }
+            }
+        }
+        write_job(today_dir, &job)?;
+        processed += 1;
+    }
+    Ok(processed)
+}
+
+/// Current status of every queued/delivered/failed job, oldest first.
+pub fn delivery_status(today_dir: &Path) -> Result<Vec<DeliveryJob>, String> {
+    read_jobs(today_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_enqueue_and_deliver_on_first_attempt() {
+        let dir = tempdir().expect("tempdir");
+        let today_dir = dir.path();
+
+        let id = enqueue_delivery(today_dir, "email", "Good morning briefing", 1_000).expect("enqueue");
+        let processed = process_spool(today_dir, 1_000, |_job| Ok(())).expect("process");
+        assert_eq!(processed, 1);
+
+        let statuses = delivery_status(today_dir).expect("status");
+        let job = statuses.iter().find(|j| j.id == id).expect("job present");
+        assert_eq!(job.status.stage, DeliveryStage::Delivered);
+    }
+
+    #[test]
+    fn test_failed_delivery_schedules_backoff_retry() {
+        let dir = tempdir().expect("tempdir");
+        let today_dir = dir.path();
+
+        enqueue_delivery(today_dir, "email", "payload", 1_000).expect("enqueue");
+        process_spool(today_dir, 1_000, |_job| {
+            Err((Some("smtp_timeout".to_string()), "Connection timed out".to_string()))
+        })
+        .expect("process");
+
+        let statuses = delivery_status(today_dir).expect("status");
+        let job = &statuses[0];
+        assert_eq!(job.status.stage, DeliveryStage::Queued);
+        assert_eq!(job.status.retry_count, 1);
+        assert_eq!(job.status.next_retry_at, Some(1_000 + BASE_BACKOFF_SECS * 2));
+
+        // Not due yet — a pass at the same `now` shouldn't re-attempt it.
+        let processed = process_spool(today_dir, 1_000, |_job| Ok(())).expect("process");
+        assert_eq!(processed, 0);
+    }
+
+    #[test]
+    fn test_job_marked_failed_after_max_attempts() {
+        let dir = tempdir().expect("tempdir");
+        let today_dir = dir.path();
+
+        enqueue_delivery(today_dir, "email", "payload", 0).expect("enqueue");
+        let mut now = 0i64;
+        for _ in 0..MAX_ATTEMPTS {
+            process_spool(today_dir, now, |_job| {
+                Err((Some("smtp_timeout".to_string()), "timed out".to_string()))
+            })
+            .expect("process");
+            now += BASE_BACKOFF_SECS * 64; // comfortably past any backoff window
+        }
+
+        let statuses = delivery_status(today_dir).expect("status");
+        assert_eq!(statuses[0].status.stage, DeliveryStage::Failed);
+        assert_eq!(statuses[0].status.next_retry_at, None);
+    }
+}
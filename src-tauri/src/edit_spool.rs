@@ -0,0 +1,320 @@
+//! Write-behind spool for local user edits (I196)
+//!
+//! `json_loader` is read-only: it rebuilds `Meeting`/`Action`/`FullMeetingPrep` fresh
+//! from whatever the generator last wrote to `_today/data/`. But a handful of fields on
+//! those types are *user-owned* state — `prep_reviewed`, `user_agenda`, `user_notes`,
+//! an action's `status` — that the UI mutates locally. Without this module, the next
+//! regeneration silently clobbers those mutations.
+//!
+//! Mutations are appended as serialized ops to `data/spool/<uuid>.json`, written via
+//! temp-file + atomic rename (modeled on a mail queue's spool) so a crash never
+//! corrupts the store. On load, ops are replayed in `ts` order on top of the freshly
+//! loaded data, keyed on each entity's `id`, then compacted once their `ts` predates
+//! the manifest's `generatedAt`.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Action, FullMeetingPrep, Meeting};
+
+/// Which collection an [`EditOp`] targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntityKind {
+    Meeting,
+    Action,
+    Prep,
+}
+
+/// A single local edit, spooled to disk until it's folded back into freshly loaded data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EditOp {
+    pub entity_kind: EntityKind,
+    pub id: String,
+    pub field: String,
+    pub value: serde_json::Value,
+    /// Unix millis. Orders replay and decides what's safe to compact.
+    pub ts: i64,
+}
+
+fn spool_dir(today_dir: &Path) -> PathBuf {
+    today_dir.join("data").join("spool")
+}
+
+/// Append a local edit to the spool as `data/spool/<uuid>.json`, via temp-file + atomic
+/// rename so a crash mid-write never corrupts the store.
+pub fn record_edit(today_dir: &Path, op: &EditOp) -> Result<(), String> {
+    let dir = spool_dir(today_dir);
+    std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create spool dir: {}", e))?;
+
+    let path = dir.join(format!("{}.json", uuid::Uuid::new_v4()));
+    let content =
+        serde_json::to_vec_pretty(op).map_err(|e| format!("Failed to serialize edit: {}", e))?;
+    crate::util::atomic_write(&path, &content)
+        .map_err(|e| format!("Failed to write spool entry: {}", e))
+}
+
+/// One spooled op read back from disk, alongside the file it came from (needed so
+/// [`compact_spool`] knows which files are safe to remove).
+struct SpooledOp {
+    path: PathBuf,
+    op: EditOp,
+}
+
+/// Read every spooled op, ordered by `ts` (ties broken by filename for determinism).
+fn read_spool(today_dir: &Path) -> Result<Vec<SpooledOp>, String> {
+    let dir = spool_dir(today_dir);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut ops = Vec::new();
+    for entry in std::fs::read_dir(&dir).map_err(|e| format!("Failed to read spool dir: {}", e))? {
+        let entry = entry.map_err(|e| format!("Failed to read spool entry: {}", e))?;
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        let op: EditOp = serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?;
+        ops.push(SpooledOp { path, op });
+    }
+    ops.sort_by(|a, b| a.op.ts.cmp(&b.op.ts).then_with(|| a.path.cmp(&b.path)));
+    Ok(ops)
+}
+
+fn apply_meeting_field(meeting: &mut Meeting, field: &str, value: &serde_json::Value) {
+    match field {
+        "prep_reviewed" => {
+            if let Ok(v) = serde_json::from_value::<bool>(value.clone()) {
+                meeting.prep_reviewed = Some(v);
+            }
+        }
+        "overlay_status" => {
+            if let Ok(v) = serde_json::from_value(value.clone()) {
+                meeting.overlay_status = Some(v);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn apply_action_field(action: &mut Action, field: &str, value: &serde_json::Value) {
+    if field == "status" {
+        if let Ok(v) = serde_json::from_value(value.clone()) {
+            action.status = v;
+        }
+    }
+}
+
+fn apply_prep_field(prep: &mut FullMeetingPrep, field: &str, value: &serde_json::Value) {
+    match field {
+        "user_agenda" => {
+            if let Ok(v) = serde_json::from_value(value.clone()) {
+                prep.user_agenda = Some(v);
+            }
+        }
+        "user_notes" => {
+            if let Ok(v) = serde_json::from_value::<String>(value.clone()) {
+                prep.user_notes = Some(v);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Replay spooled ops on top of freshly-loaded day data, keyed on each entity's `id`
+/// (a prep's `id` is its `calendar_event_id`). Ops targeting an entity absent from the
+/// freshly loaded set are skipped — that entity no longer exists in today's regeneration.
+pub fn replay_edits(
+    today_dir: &Path,
+    meetings: &mut [Meeting],
+    actions: &mut [Action],
+    preps: &mut [FullMeetingPrep],
+) -> Result<(), String> {
+    let ops = read_spool(today_dir)?;
+    for spooled in &ops {
+        let op = &spooled.op;
+        match op.entity_kind {
+            EntityKind::Meeting => {
+                if let Some(m) = meetings.iter_mut().find(|m| m.id == op.id) {
+                    apply_meeting_field(m, &op.field, &op.value);
+                }
+            }
+            EntityKind::Action => {
+                if let Some(a) = actions.iter_mut().find(|a| a.id == op.id) {
+                    apply_action_field(a, &op.field, &op.value);
+                }
+            }
+            EntityKind::Prep => {
+                if let Some(p) = preps
+                    .iter_mut()
+                    .find(|p| p.calendar_event_id.as_deref() == Some(op.id.as_str()))
+                {
+                    apply_prep_field(p, &op.field, &op.value);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Remove spooled ops whose `ts` predates `generated_at` (an RFC 3339 timestamp, as
+/// found in `Manifest.generated_at`). Those ops have already been folded into the data
+/// the generator produced, so keeping them around would just re-apply stale values.
+pub fn compact_spool(today_dir: &Path, generated_at: &str) -> Result<usize, String> {
+    let cutoff = chrono::DateTime::parse_from_rfc3339(generated_at)
+        .map_err(|e| format!("Invalid generatedAt timestamp: {}", e))?
+        .timestamp_millis();
+
+    let ops = read_spool(today_dir)?;
+    let mut removed = 0;
+    for spooled in ops {
+        if spooled.op.ts < cutoff {
+            std::fs::remove_file(&spooled.path)
+                .map_err(|e| format!("Failed to remove {}: {}", spooled.path.display(), e))?;
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+/// Load the day's meetings/actions/preps and replay any spooled local edits on top,
+/// so user-owned state (`prep_reviewed`, `user_agenda`, `user_notes`, action `status`)
+/// survives the generator rewriting the underlying JSON.
+pub fn load_day_with_edits(
+    today_dir: &Path,
+) -> Result<(Vec<Meeting>, Vec<Action>, Vec<FullMeetingPrep>), String> {
+    let (_, mut meetings) = crate::json_loader::load_schedule_json(today_dir)?;
+    let mut actions = crate::json_loader::load_actions_json(today_dir)?;
+
+    let mut preps = Vec::new();
+    for meeting in &meetings {
+        if let Some(prep_file) = meeting.prep_file.as_deref().filter(|_| meeting.has_prep) {
+            if let Ok(prep) = crate::json_loader::load_prep_json(today_dir, prep_file) {
+                preps.push(prep);
+            }
+        }
+    }
+
+    replay_edits(today_dir, &mut meetings, &mut actions, &mut preps)?;
+
+    if let Ok(manifest) = crate::json_loader::load_manifest(today_dir) {
+        let _ = compact_spool(today_dir, &manifest.generated_at);
+    }
+
+    Ok((meetings, actions, preps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn meeting(id: &str) -> Meeting {
+        Meeting {
+            id: id.to_string(),
+            calendar_event_id: Some(id.to_string()),
+            time: "09:00".to_string(),
+            end_time: None,
+            start_iso: None,
+            title: "Sync".to_string(),
+            meeting_type: crate::types::MeetingType::Internal,
+            account: None,
+            prep: None,
+            is_current: None,
+            prep_file: None,
+            has_prep: false,
+            overlay_status: None,
+            prep_reviewed: None,
+            account_id: None,
+            linked_entities: None,
+            suggested_unarchive_account_id: None,
+        }
+    }
+
+    #[test]
+    fn test_record_and_replay_meeting_edit() {
+        let dir = tempdir().expect("tempdir");
+        let today_dir = dir.path();
+
+        let op = EditOp {
+            entity_kind: EntityKind::Meeting,
+            id: "m1".to_string(),
+            field: "prep_reviewed".to_string(),
+            value: serde_json::json!(true),
+            ts: 1000,
+        };
+        record_edit(today_dir, &op).expect("record edit");
+
+        let mut meetings = vec![meeting("m1")];
+        let mut actions = Vec::new();
+        let mut preps = Vec::new();
+        replay_edits(today_dir, &mut meetings, &mut actions, &mut preps).expect("replay");
+
+        assert_eq!(meetings[0].prep_reviewed, Some(true));
+    }
+
+    #[test]
+    fn test_replay_applies_ops_in_ts_order() {
+        let dir = tempdir().expect("tempdir");
+        let today_dir = dir.path();
+
+        record_edit(
+            today_dir,
+            &EditOp {
+                entity_kind: EntityKind::Meeting,
+                id: "m1".to_string(),
+                field: "prep_reviewed".to_string(),
+                value: serde_json::json!(true),
+                ts: 2000,
+            },
+        )
+        .expect("record first edit");
+        record_edit(
+            today_dir,
+            &EditOp {
+                entity_kind: EntityKind::Meeting,
+                id: "m1".to_string(),
+                field: "prep_reviewed".to_string(),
+                value: serde_json::json!(false),
+                ts: 1000,
+            },
+        )
+        .expect("record second edit");
+
+        let mut meetings = vec![meeting("m1")];
+        let mut actions = Vec::new();
+        let mut preps = Vec::new();
+        replay_edits(today_dir, &mut meetings, &mut actions, &mut preps).expect("replay");
+
+        // The ts=2000 op is applied last, so its value wins.
+        assert_eq!(meetings[0].prep_reviewed, Some(true));
+    }
+
+    #[test]
+    fn test_compact_spool_removes_ops_older_than_manifest() {
+        let dir = tempdir().expect("tempdir");
+        let today_dir = dir.path();
+
+        record_edit(
+            today_dir,
+            &EditOp {
+                entity_kind: EntityKind::Meeting,
+                id: "m1".to_string(),
+                field: "prep_reviewed".to_string(),
+                value: serde_json::json!(true),
+                ts: 0,
+            },
+        )
+        .expect("record edit");
+
+        let removed = compact_spool(today_dir, "2026-07-30T12:00:00Z").expect("compact");
+        assert_eq!(removed, 1);
+        assert!(read_spool(today_dir).expect("read spool").is_empty());
+    }
+}
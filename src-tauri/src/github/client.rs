@@ -0,0 +1,111 @@
+//! REST client for the GitHub Issues API.
+//!
+//! Uses reqwest with Bearer token auth against `https://api.github.com`.
+
+use serde::Deserialize;
+
+use super::IssueId;
+
+const GITHUB_API_URL: &str = "https://api.github.com";
+
+pub struct GitHubClient {
+    client: reqwest::Client,
+    token: String,
+}
+
+impl GitHubClient {
+    pub fn new(token: &str) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            token: token.to_string(),
+        }
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: serde_json::Value,
+    ) -> Result<T, String> {
+        let resp = self
+            .client
+            .request(method, format!("{}{}", GITHUB_API_URL, path))
+            .header("Authorization", format!("Bearer {}", self.token))
+            .header("Accept", "application/vnd.github+json")
+            .header("User-Agent", "daily-operating-system")
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| format!("GitHub API request failed: {}", e))?;
+
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let text = resp.text().await.unwrap_or_default();
+            return Err(format!("GitHub API error {}: {}", status, text));
+        }
+
+        resp.json::<T>()
+            .await
+            .map_err(|e| format!("Failed to deserialize GitHub response: {}", e))
+    }
+
+    /// Create a new issue in `repository` (`owner/repo`), returning its `IssueId`.
+    pub async fn create_issue(
+        &self,
+        repository: &str,
+        title: &str,
+        body: &str,
+        labels: &[String],
+    ) -> Result<IssueId, String> {
+        #[derive(Deserialize)]
+        struct CreatedIssue {
+            number: u64,
+        }
+
+        let (owner, repo) = split_repository(repository)?;
+        let created: CreatedIssue = self
+            .request(
+                reqwest::Method::POST,
+                &format!("/repos/{}/{}/issues", owner, repo),
+                serde_json::json!({ "title": title, "body": body, "labels": labels }),
+            )
+            .await?;
+
+        Ok(IssueId {
+            repository: repository.to_string(),
+            number: created.number,
+        })
+    }
+
+    /// Update an existing issue's title, body, and labels.
+    pub async fn update_issue(
+        &self,
+        issue: &IssueId,
+        title: &str,
+        body: &str,
+        labels: &[String],
+    ) -> Result<(), String> {
+        #[derive(Deserialize)]
+        struct UpdatedIssue {
+            #[allow(dead_code)]
+            number: u64,
+        }
+
+        let (owner, repo) = split_repository(&issue.repository)?;
+        let _: UpdatedIssue = self
+            .request(
+                reqwest::Method::PATCH,
+                &format!("/repos/{}/{}/issues/{}", owner, repo, issue.number),
+                serde_json::json!({ "title": title, "body": body, "labels": labels }),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn split_repository(repository: &str) -> Result<(&str, &str), String> {
+    repository
+        .split_once('/')
+        .ok_or_else(|| format!("Expected 'owner/repo', got '{}'", repository))
+}
@@ -0,0 +1,115 @@
+//! GitHub issue-tracker integration (chunk199-1).
+//!
+//! [`IssueId`] identifies a single GitHub issue as `owner/repo#number` — the
+//! same form GitHub itself uses when cross-linking issues. The transcript
+//! pipeline (`processor::transcript::issues`) records one of these on each
+//! action line once an issue is created, so a re-run recognizes the line as
+//! already tracked instead of filing a duplicate.
+//!
+//! Configured via `GITHUB_TOKEN`/`GITHUB_REPOSITORY` env vars rather than
+//! `~/.dailyos/config.json` — the same way `processor::transcript::otel`
+//! reads `OTEL_EXPORTER_OTLP_ENDPOINT` instead of a dedicated setting. There's
+//! no settings UI for this yet, and env vars keep the token out of the
+//! config file.
+
+pub mod client;
+
+use std::str::FromStr;
+
+/// GitHub issue sync configuration, read from the environment.
+#[derive(Debug, Clone, Default)]
+pub struct GitHubConfig {
+    pub token: String,
+    pub repository: String,
+}
+
+impl GitHubConfig {
+    /// Build from `GITHUB_TOKEN`/`GITHUB_REPOSITORY` env vars. Returns `None`
+    /// if either is unset — issue sync is opt-in and best-effort, never a
+    /// reason to fail transcript processing.
+    pub fn from_env() -> Option<Self> {
+        let token = std::env::var("GITHUB_TOKEN").ok().filter(|v| !v.is_empty())?;
+        let repository = std::env::var("GITHUB_REPOSITORY")
+            .ok()
+            .filter(|v| !v.is_empty())?;
+        Some(Self { token, repository })
+    }
+}
+
+/// A single GitHub issue, identified the way GitHub itself cross-links them:
+/// `owner/repo#123`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IssueId {
+    pub repository: String,
+    pub number: u64,
+}
+
+impl std::fmt::Display for IssueId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}#{}", self.repository, self.number)
+    }
+}
+
+impl FromStr for IssueId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (repository, number) = s
+            .rsplit_once('#')
+            .ok_or_else(|| format!("expected 'owner/repo#number', got '{}'", s))?;
+        if repository.split('/').count() != 2 {
+            return Err(format!("expected 'owner/repo#number', got '{}'", s));
+        }
+        let number = number
+            .parse::<u64>()
+            .map_err(|_| format!("invalid issue number in '{}'", s))?;
+        Ok(Self {
+            repository: repository.to_string(),
+            number,
+        })
+    }
+}
+
+/// Map an action's `P0`-`P3` priority to a GitHub label. Unrecognized or
+/// missing priorities fall back to the same `P2` default
+/// `extract_transcript_actions` uses for untagged actions.
+pub fn priority_label(priority: &str) -> &'static str {
+    match priority {
+        "P0" => "priority:p0",
+        "P1" => "priority:p1",
+        "P3" => "priority:p3",
+        _ => "priority:p2",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issue_id_roundtrips_through_display_and_from_str() {
+        let issue = IssueId {
+            repository: "acme/crm".to_string(),
+            number: 42,
+        };
+        assert_eq!(issue.to_string(), "acme/crm#42");
+        assert_eq!(issue.to_string().parse::<IssueId>().unwrap(), issue);
+    }
+
+    #[test]
+    fn test_issue_id_rejects_malformed_input() {
+        assert!("acme/crm".parse::<IssueId>().is_err());
+        assert!("acme#42".parse::<IssueId>().is_err());
+        assert!("acme/crm#abc".parse::<IssueId>().is_err());
+        assert!("owner/repo/extra#1".parse::<IssueId>().is_err());
+    }
+
+    #[test]
+    fn test_priority_label_maps_p0_through_p3() {
+        assert_eq!(priority_label("P0"), "priority:p0");
+        assert_eq!(priority_label("P1"), "priority:p1");
+        assert_eq!(priority_label("P2"), "priority:p2");
+        assert_eq!(priority_label("P3"), "priority:p3");
+        assert_eq!(priority_label("unknown"), "priority:p2");
+    }
+}
@@ -36,9 +36,21 @@ pub async fn run_granola_poller(state: Arc<AppState>, app_handle: AppHandle) {
 
         let poll_interval = Duration::from_secs((config.poll_interval_minutes as u64) * 60);
 
-        // Read and process the cache
-        if let Err(e) = poll_once(&state, &app_handle, &config.cache_path) {
-            log::warn!("Granola poller: {}", e);
+        // Read and process the cache. `poll_once` runs the transcript pipeline
+        // synchronously (including a GitHub issue-sync step that does its own
+        // `handle.block_on`), so it has to run off this worker thread — calling
+        // it inline here would panic the moment `block_on` nested inside one.
+        let poll_state = state.clone();
+        let poll_handle = app_handle.clone();
+        let cache_path = config.cache_path.clone();
+        let poll_result =
+            tokio::task::spawn_blocking(move || poll_once(&poll_state, &poll_handle, &cache_path))
+                .await;
+
+        match poll_result {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => log::warn!("Granola poller: {}", e),
+            Err(e) => log::warn!("Granola poller: blocking task panicked: {}", e),
         }
 
         tokio::time::sleep(poll_interval).await;
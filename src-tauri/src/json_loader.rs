@@ -11,6 +11,9 @@
 use std::fs;
 use std::path::Path;
 
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
 use crate::types::{
     Action, DayOverview, Email, EmailSyncStatus, FullMeetingPrep, LinkedEntity, Meeting,
     MeetingPrep, WeekOverview,
@@ -742,7 +745,7 @@ pub fn load_prep_json(today_dir: &Path, prep_file: &str) -> Result<FullMeetingPr
 ///
 /// Uses serde defaults throughout so missing keys don't cause parse failures.
 /// The Rust delivery functions read what they need; unknown fields are ignored.
-#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
 pub struct Directive {
     #[serde(default)]
     pub context: DirectiveContext,
@@ -758,7 +761,7 @@ pub struct Directive {
     pub emails: DirectiveEmails,
 }
 
-#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
 pub struct DirectiveContext {
     #[serde(default)]
     pub date: Option<String>,
@@ -772,13 +775,13 @@ pub struct DirectiveContext {
     pub focus: Option<String>,
 }
 
-#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
 pub struct DirectiveCalendar {
     #[serde(default)]
     pub events: Vec<DirectiveEvent>,
 }
 
-#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
 pub struct DirectiveEvent {
     #[serde(default)]
     pub id: Option<String>,
@@ -790,7 +793,7 @@ pub struct DirectiveEvent {
     pub end: Option<String>,
 }
 
-#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
 pub struct DirectiveMeeting {
     #[serde(default)]
     pub id: Option<String>,
@@ -817,7 +820,7 @@ pub struct DirectiveMeeting {
     pub entities: Vec<serde_json::Value>,
 }
 
-#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
 pub struct DirectiveMeetingContext {
     #[serde(default)]
     pub event_id: Option<String>,
@@ -896,7 +899,7 @@ pub struct DirectiveMeetingContext {
     pub pre_meeting_email_context: Option<Vec<serde_json::Value>>,
 }
 
-#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
 pub struct DirectiveActions {
     #[serde(default)]
     pub overdue: Vec<DirectiveAction>,
@@ -908,7 +911,7 @@ pub struct DirectiveActions {
     pub waiting_on: Vec<DirectiveWaiting>,
 }
 
-#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
 pub struct DirectiveAction {
     #[serde(default)]
     pub title: Option<String>,
@@ -933,7 +936,7 @@ impl DirectiveAction {
     }
 }
 
-#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
 pub struct DirectiveWaiting {
     #[serde(default)]
     pub what: Option<String>,
@@ -943,7 +946,7 @@ pub struct DirectiveWaiting {
     pub context: Option<String>,
 }
 
-#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
 pub struct DirectiveEmails {
     #[serde(default)]
     pub classified: Vec<DirectiveEmail>,
@@ -979,7 +982,7 @@ pub struct DirectiveReplyNeeded {
     pub wait_duration: Option<String>,
 }
 
-#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
 pub struct DirectiveEmail {
     #[serde(default)]
     pub id: Option<String>,
@@ -995,7 +998,7 @@ pub struct DirectiveEmail {
     pub priority: Option<String>,
 }
 
-#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DirectiveEmailSyncError {
     #[serde(default)]
@@ -1031,6 +1034,153 @@ pub fn load_directive(today_dir: &Path) -> Result<Directive, String> {
     serde_json::from_str(&content).map_err(|e| format!("Failed to parse directive: {}", e))
 }
 
+/// A top-level section of `today-directive.json` that failed to salvage during
+/// [`load_directive_lenient`], identified by its JSON Pointer path.
+#[derive(Debug, Clone)]
+pub struct DirectiveParseWarning {
+    pub pointer: String,
+    pub message: String,
+}
+
+fn salvage_section<T: Default + serde::de::DeserializeOwned>(
+    obj: &serde_json::Map<String, serde_json::Value>,
+    key: &str,
+    warnings: &mut Vec<DirectiveParseWarning>,
+) -> T {
+    match obj.get(key) {
+        None => T::default(),
+        Some(section) => match serde_json::from_value(section.clone()) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                warnings.push(DirectiveParseWarning {
+                    pointer: format!("/{}", key),
+                    message: e.to_string(),
+                });
+                T::default()
+            }
+        },
+    }
+}
+
+/// Load `today-directive.json`, salvaging whatever top-level sections parse even when
+/// others don't.
+///
+/// First attempts the strict [`Directive`] deserialize (same as [`load_directive`]). On
+/// failure, reparses the raw bytes into a `serde_json::Value` and salvages each
+/// top-level section (`context`, `calendar`, `meetings`, `meeting_contexts`, `actions`,
+/// `emails`) independently, defaulting any section that fails on its own. Salvage mode
+/// never fails outright as long as the top-level JSON is an object — callers get a
+/// best-effort directive plus a diagnostics list instead of a hard error.
+pub fn load_directive_lenient(
+    today_dir: &Path,
+) -> Result<(Directive, Vec<DirectiveParseWarning>), String> {
+    let primary = today_dir.join("data").join("today-directive.json");
+    let legacy = today_dir.join(".today-directive.json");
+
+    let path = if primary.exists() {
+        &primary
+    } else if legacy.exists() {
+        &legacy
+    } else {
+        return Err(format!(
+            "Directive not found. Checked:\n  {}\n  {}",
+            primary.display(),
+            legacy.display()
+        ));
+    };
+
+    let content =
+        fs::read_to_string(path).map_err(|e| format!("Failed to read directive: {}", e))?;
+
+    if let Ok(directive) = serde_json::from_str::<Directive>(&content) {
+        return Ok((directive, Vec::new()));
+    }
+
+    let value: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse directive as JSON: {}", e))?;
+    let obj = value
+        .as_object()
+        .ok_or_else(|| "Directive root is not a JSON object".to_string())?;
+
+    let mut warnings = Vec::new();
+    let directive = Directive {
+        context: salvage_section(obj, "context", &mut warnings),
+        calendar: salvage_section(obj, "calendar", &mut warnings),
+        meetings: salvage_section(obj, "meetings", &mut warnings),
+        meeting_contexts: salvage_section(obj, "meeting_contexts", &mut warnings),
+        actions: salvage_section(obj, "actions", &mut warnings),
+        emails: salvage_section(obj, "emails", &mut warnings),
+    };
+
+    Ok((directive, warnings))
+}
+
+/// Recursively sort object keys and drop `null`/empty-array values so two JSON values
+/// that differ only in key order or explicit-null-vs-missing compare equal once
+/// canonicalized.
+fn canonicalize_json(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            let mut sorted = serde_json::Map::new();
+            for key in keys {
+                let canonical = canonicalize_json(map[key].clone());
+                match &canonical {
+                    serde_json::Value::Null => continue,
+                    serde_json::Value::Array(a) if a.is_empty() => continue,
+                    _ => {}
+                }
+                sorted.insert(key.clone(), canonical);
+            }
+            serde_json::Value::Object(sorted)
+        }
+        serde_json::Value::Array(arr) => {
+            serde_json::Value::Array(arr.into_iter().map(canonicalize_json).collect())
+        }
+        other => other,
+    }
+}
+
+impl Directive {
+    /// A deterministic content hash used to detect whether the directive actually
+    /// changed between renders, so delivery functions can skip regenerating output.
+    ///
+    /// Serializes to canonical JSON — object keys sorted lexicographically at every
+    /// level, no insignificant whitespace, `Option::None`/empty-vec fields omitted —
+    /// then SHA-256s the canonical bytes and returns the hex digest. Two directives
+    /// that differ only in field order or in explicit-null-vs-missing hash identically,
+    /// since Phase 1 emits keys in nondeterministic order.
+    pub fn content_hash(&self) -> String {
+        let value = serde_json::to_value(self).unwrap_or(serde_json::Value::Null);
+        let canonical = canonicalize_json(value);
+        let bytes = serde_json::to_vec(&canonical).unwrap_or_default();
+        let mut hasher = Sha256::new();
+        hasher.update(&bytes);
+        hex::encode(hasher.finalize())
+    }
+}
+
+/// Path to the file recording the last rendered directive's content hash, so a caller
+/// can compare before regenerating delivery output.
+fn directive_hash_path(today_dir: &Path) -> std::path::PathBuf {
+    today_dir.join("data").join(".directive-hash")
+}
+
+/// Persist `hash` as the last-rendered directive digest.
+pub fn save_directive_hash(today_dir: &Path, hash: &str) -> Result<(), String> {
+    crate::util::atomic_write_str(&directive_hash_path(today_dir), hash)
+        .map_err(|e| format!("Failed to write directive hash: {}", e))
+}
+
+/// Read the last-rendered directive digest, if one was persisted by
+/// [`save_directive_hash`].
+pub fn load_directive_hash(today_dir: &Path) -> Option<String> {
+    fs::read_to_string(directive_hash_path(today_dir))
+        .ok()
+        .map(|s| s.trim().to_string())
+}
+
 // =============================================================================
 // Week JSON Loading (Phase 3C)
 // =============================================================================
@@ -1043,6 +1193,296 @@ pub fn load_week_json(today_dir: &Path) -> Result<WeekOverview, String> {
     serde_json::from_str(&content).map_err(|e| format!("Failed to parse week overview: {}", e))
 }
 
+// =============================================================================
+// Typed Loader Errors + Partial-Load Recovery
+// =============================================================================
+
+/// A typed failure from one of the `load_*_json` functions, in place of the stringly-typed
+/// `Result<_, String>` those functions return today. [`load_day`] collects these per
+/// category instead of aborting the whole refresh on one bad file.
+#[derive(Debug, Error)]
+pub enum LoaderError {
+    #[error("File missing")]
+    FileMissing,
+
+    #[error("IO error: {0}")]
+    Io(String),
+
+    #[error("Failed to parse {file}: {detail}")]
+    Parse { file: String, detail: String },
+}
+
+impl LoaderError {
+    /// Classify a `load_*_json` string error into a typed `LoaderError` for the given file.
+    fn from_load_result(file: &str, message: String) -> Self {
+        if message.starts_with("Failed to read") {
+            if message.contains("No such file") || message.contains("os error 2") {
+                LoaderError::FileMissing
+            } else {
+                LoaderError::Io(message)
+            }
+        } else {
+            LoaderError::Parse {
+                file: file.to_string(),
+                detail: message,
+            }
+        }
+    }
+}
+
+/// Which category of day data a [`LoaderError`] applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Category {
+    Schedule,
+    Actions,
+    Emails,
+    Preps,
+    Week,
+}
+
+/// Result of attempting to load every category of `_today/data` independently.
+///
+/// Unlike the individual `load_*_json` functions, `load_day` never aborts on the first
+/// bad file: each category is attempted on its own, successes are collected, and
+/// failures are recorded alongside which category they belong to. A UI can render
+/// whatever loaded and show exactly which feeds are degraded.
+///
+/// This is JSON-only: a failed category is recorded in `failures` and left empty/`None`
+/// in the report, not silently recovered from the legacy markdown files `parser.rs`
+/// can read. There's no markdown fallback wired into this path today.
+#[derive(Debug, Default)]
+pub struct LoadReport {
+    pub overview: Option<DayOverview>,
+    pub meetings: Vec<Meeting>,
+    pub actions: Vec<Action>,
+    pub emails: Vec<Email>,
+    pub preps: Vec<FullMeetingPrep>,
+    pub week: Option<WeekOverview>,
+    pub failures: Vec<(Category, LoaderError)>,
+}
+
+impl LoadReport {
+    /// Whether every category loaded successfully from JSON.
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Attempt to load every category of day data independently, collecting successes
+/// alongside a list of per-category failures rather than aborting on the first error.
+pub fn load_day(today_dir: &Path) -> LoadReport {
+    let mut report = LoadReport::default();
+
+    match load_schedule_json(today_dir) {
+        Ok((overview, meetings)) => {
+            report.overview = Some(overview);
+            report.meetings = meetings;
+        }
+        Err(e) => report
+            .failures
+            .push((Category::Schedule, LoaderError::from_load_result("schedule.json", e))),
+    }
+
+    for meeting in &report.meetings {
+        let Some(prep_file) = meeting.prep_file.as_deref().filter(|_| meeting.has_prep) else {
+            continue;
+        };
+        match load_prep_json(today_dir, prep_file) {
+            Ok(prep) => report.preps.push(prep),
+            Err(e) => report
+                .failures
+                .push((Category::Preps, LoaderError::from_load_result(prep_file, e))),
+        }
+    }
+
+    match load_actions_json(today_dir) {
+        Ok(actions) => report.actions = actions,
+        Err(e) => report
+            .failures
+            .push((Category::Actions, LoaderError::from_load_result("actions.json", e))),
+    }
+
+    match load_emails_json(today_dir) {
+        Ok(emails) => report.emails = emails,
+        Err(e) => report
+            .failures
+            .push((Category::Emails, LoaderError::from_load_result("emails.json", e))),
+    }
+
+    match load_week_json(today_dir) {
+        Ok(week) => report.week = Some(week),
+        Err(e) => report.failures.push((
+            Category::Week,
+            LoaderError::from_load_result("week-overview.json", e),
+        )),
+    }
+
+    report
+}
+
+// =============================================================================
+// Predicate Filtering (saved "views" over loaded day data)
+// =============================================================================
+
+/// A small, serde-deserializable predicate tree for filtering `Meeting`/`Action`/`Email`
+/// collections at load time, modeled on event-selection DSLs.
+///
+/// String comparisons (`*Equals`, `MeetingTypeIn`) are case-insensitive. A predicate is
+/// evaluated per-entity; leaf variants that don't apply to the entity being matched
+/// (e.g. `IsOverdue` against an `Email`) simply don't match rather than erroring.
+///
+/// Predicates round-trip through JSON so a saved "view" can be persisted to disk and
+/// reloaded with [`load_predicate_json`].
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Predicate {
+    AnyOf(Vec<Predicate>),
+    AllOf(Vec<Predicate>),
+    Not(Box<Predicate>),
+    MeetingTypeIn(Vec<String>),
+    PriorityEquals(String),
+    AccountEquals(String),
+    StatusEquals(String),
+    IsOverdue,
+    HasPrep,
+    HasCommitments,
+    SentimentEquals(String),
+}
+
+impl Default for Predicate {
+    /// The empty conjunction — matches everything. Used when a view has no predicate.
+    fn default() -> Self {
+        Predicate::AllOf(Vec::new())
+    }
+}
+
+fn priority_code(priority: &crate::types::Priority) -> &'static str {
+    match priority {
+        crate::types::Priority::P1 => "p1",
+        crate::types::Priority::P2 => "p2",
+        crate::types::Priority::P3 => "p3",
+    }
+}
+
+fn action_status_code(status: &crate::types::ActionStatus) -> &'static str {
+    match status {
+        crate::types::ActionStatus::Pending => "pending",
+        crate::types::ActionStatus::Completed => "completed",
+    }
+}
+
+impl Predicate {
+    /// Walk the predicate tree, delegating leaf evaluation to `leaf`. Structural
+    /// variants (`AnyOf`/`AllOf`/`Not`) are handled once here; `leaf` only needs to
+    /// answer the entity-specific comparisons, returning `false` for anything that
+    /// doesn't apply to the entity it was called for.
+    fn eval(&self, leaf: &impl Fn(&Predicate) -> bool) -> bool {
+        match self {
+            Predicate::AnyOf(preds) => preds.iter().any(|p| p.eval(leaf)),
+            Predicate::AllOf(preds) => preds.iter().all(|p| p.eval(leaf)),
+            Predicate::Not(inner) => !inner.eval(leaf),
+            other => leaf(other),
+        }
+    }
+
+    pub fn matches_meeting(&self, meeting: &Meeting) -> bool {
+        self.eval(&|p| match p {
+            Predicate::MeetingTypeIn(types) => types
+                .iter()
+                .any(|t| t.eq_ignore_ascii_case(meeting.meeting_type.as_str())),
+            Predicate::AccountEquals(account) => meeting
+                .account
+                .as_deref()
+                .is_some_and(|a| a.eq_ignore_ascii_case(account)),
+            Predicate::HasPrep => meeting.has_prep,
+            Predicate::HasCommitments => meeting
+                .prep
+                .as_ref()
+                .and_then(|p| p.actions.as_ref())
+                .is_some_and(|a| !a.is_empty()),
+            _ => false,
+        })
+    }
+
+    pub fn matches_action(&self, action: &Action) -> bool {
+        self.eval(&|p| match p {
+            Predicate::PriorityEquals(priority) => {
+                priority.eq_ignore_ascii_case(priority_code(&action.priority))
+            }
+            Predicate::AccountEquals(account) => action
+                .account
+                .as_deref()
+                .is_some_and(|a| a.eq_ignore_ascii_case(account)),
+            Predicate::StatusEquals(status) => {
+                status.eq_ignore_ascii_case(action_status_code(&action.status))
+            }
+            Predicate::IsOverdue => action.is_overdue.unwrap_or(false),
+            _ => false,
+        })
+    }
+
+    pub fn matches_email(&self, email: &Email) -> bool {
+        self.eval(&|p| match p {
+            Predicate::HasCommitments => !email.commitments.is_empty(),
+            Predicate::SentimentEquals(sentiment) => email
+                .sentiment
+                .as_deref()
+                .is_some_and(|s| s.eq_ignore_ascii_case(sentiment)),
+            _ => false,
+        })
+    }
+}
+
+/// Load a saved predicate "view" from a JSON file. An absent file matches everything
+/// (equivalent to [`Predicate::default`]), so callers don't need to special-case the
+/// no-filter case.
+pub fn load_predicate_json(path: &Path) -> Result<Predicate, String> {
+    if !path.exists() {
+        return Ok(Predicate::default());
+    }
+    let content =
+        fs::read_to_string(path).map_err(|e| format!("Failed to read predicate: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse predicate: {}", e))
+}
+
+/// Load schedule from JSON, dropping meetings that don't match `predicate`.
+pub fn load_schedule_json_filtered(
+    today_dir: &Path,
+    predicate: &Predicate,
+) -> Result<(DayOverview, Vec<Meeting>), String> {
+    let (overview, meetings) = load_schedule_json(today_dir)?;
+    let meetings = meetings
+        .into_iter()
+        .filter(|m| predicate.matches_meeting(m))
+        .collect();
+    Ok((overview, meetings))
+}
+
+/// Load actions from JSON, dropping actions that don't match `predicate`.
+pub fn load_actions_json_filtered(
+    today_dir: &Path,
+    predicate: &Predicate,
+) -> Result<Vec<Action>, String> {
+    let actions = load_actions_json(today_dir)?;
+    Ok(actions
+        .into_iter()
+        .filter(|a| predicate.matches_action(a))
+        .collect())
+}
+
+/// Load emails from JSON, dropping emails that don't match `predicate`.
+pub fn load_emails_json_filtered(
+    today_dir: &Path,
+    predicate: &Predicate,
+) -> Result<Vec<Email>, String> {
+    let emails = load_emails_json(today_dir)?;
+    Ok(emails
+        .into_iter()
+        .filter(|e| predicate.matches_email(e))
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1175,4 +1615,184 @@ mod tests {
         assert_eq!(sync.code.as_deref(), Some("gmail_auth_failed"));
         assert_eq!(sync.using_last_known_good, Some(true));
     }
+
+    #[test]
+    fn test_load_schedule_json_filtered_applies_predicate() {
+        let dir = tempdir().expect("tempdir");
+        let today_dir = dir.path();
+        let data_dir = today_dir.join("data");
+        fs::create_dir_all(&data_dir).expect("create data dir");
+
+        fs::write(
+            data_dir.join("schedule.json"),
+            serde_json::to_string_pretty(&json!({
+                "date": "2026-07-30",
+                "meetings": [
+                    {"id": "m1", "time": "09:00", "title": "Acme Sync", "type": "customer", "account": "Acme", "hasPrep": true},
+                    {"id": "m2", "time": "10:00", "title": "Team Standup", "type": "internal", "hasPrep": false}
+                ]
+            }))
+            .unwrap(),
+        )
+        .expect("write schedule");
+
+        let predicate = Predicate::MeetingTypeIn(vec!["customer".to_string()]);
+        let (_, meetings) = load_schedule_json_filtered(today_dir, &predicate).expect("load");
+        assert_eq!(meetings.len(), 1);
+        assert_eq!(meetings[0].id, "m1");
+    }
+
+    #[test]
+    fn test_default_predicate_matches_everything() {
+        let predicate = Predicate::default();
+        let meeting = Meeting {
+            id: "m1".to_string(),
+            calendar_event_id: None,
+            time: "09:00".to_string(),
+            end_time: None,
+            start_iso: None,
+            title: "Internal Sync".to_string(),
+            meeting_type: crate::types::MeetingType::Internal,
+            account: None,
+            prep: None,
+            is_current: None,
+            prep_file: None,
+            has_prep: false,
+            overlay_status: None,
+            prep_reviewed: None,
+            account_id: None,
+            linked_entities: None,
+            suggested_unarchive_account_id: None,
+        };
+        assert!(predicate.matches_meeting(&meeting));
+    }
+
+    #[test]
+    fn test_not_and_any_of_predicate_composition() {
+        let action = Action {
+            id: "a1".to_string(),
+            title: "Renew contract".to_string(),
+            account: Some("Acme".to_string()),
+            due_date: None,
+            priority: crate::types::Priority::P1,
+            status: crate::types::ActionStatus::Pending,
+            is_overdue: Some(true),
+            context: None,
+            source: None,
+            days_overdue: Some(3),
+        };
+
+        let overdue_or_completed = Predicate::AnyOf(vec![
+            Predicate::IsOverdue,
+            Predicate::StatusEquals("completed".to_string()),
+        ]);
+        assert!(overdue_or_completed.matches_action(&action));
+
+        let not_overdue = Predicate::Not(Box::new(Predicate::IsOverdue));
+        assert!(!not_overdue.matches_action(&action));
+    }
+
+    #[test]
+    fn test_load_directive_lenient_salvages_good_sections() {
+        let dir = tempdir().expect("tempdir");
+        let today_dir = dir.path();
+        let data_dir = today_dir.join("data");
+        fs::create_dir_all(&data_dir).expect("create data dir");
+
+        fs::write(
+            data_dir.join("today-directive.json"),
+            serde_json::to_string_pretty(&json!({
+                "context": {"date": "2026-07-30", "profile": "default"},
+                "meetings": {"acme": [{"id": "not-an-object-list-entry"}]},
+                "meeting_contexts": "this should be an array, not a string"
+            }))
+            .unwrap(),
+        )
+        .expect("write directive");
+
+        let (directive, warnings) = load_directive_lenient(today_dir).expect("load directive");
+        assert_eq!(directive.context.date.as_deref(), Some("2026-07-30"));
+        assert!(directive.meeting_contexts.is_empty());
+        assert!(warnings.iter().any(|w| w.pointer == "/meeting_contexts"));
+    }
+
+    #[test]
+    fn test_content_hash_ignores_field_order_and_explicit_null() {
+        let mut a = Directive::default();
+        a.context.date = Some("2026-07-30".to_string());
+        a.context.profile = Some("default".to_string());
+
+        // Built by deserializing JSON with keys in a different order, and an
+        // explicit null for `focus` instead of it being absent.
+        let b: Directive = serde_json::from_value(json!({
+            "context": {
+                "profile": "default",
+                "focus": null,
+                "date": "2026-07-30"
+            }
+        }))
+        .expect("deserialize directive");
+
+        assert_eq!(a.content_hash(), b.content_hash());
+    }
+
+    #[test]
+    fn test_content_hash_changes_with_content() {
+        let mut a = Directive::default();
+        a.context.date = Some("2026-07-30".to_string());
+        let mut b = Directive::default();
+        b.context.date = Some("2026-07-31".to_string());
+
+        assert_ne!(a.content_hash(), b.content_hash());
+    }
+
+    #[test]
+    fn test_save_and_load_directive_hash_round_trip() {
+        let dir = tempdir().expect("tempdir");
+        let today_dir = dir.path();
+        fs::create_dir_all(today_dir.join("data")).expect("create data dir");
+
+        save_directive_hash(today_dir, "abc123").expect("save hash");
+        assert_eq!(load_directive_hash(today_dir), Some("abc123".to_string()));
+    }
+
+    #[test]
+    fn test_load_day_collects_partial_failures() {
+        let dir = tempdir().expect("tempdir");
+        let today_dir = dir.path();
+        let data_dir = today_dir.join("data");
+        fs::create_dir_all(&data_dir).expect("create data dir");
+
+        fs::write(
+            data_dir.join("schedule.json"),
+            serde_json::to_string_pretty(&json!({
+                "date": "2026-07-30",
+                "meetings": []
+            }))
+            .unwrap(),
+        )
+        .expect("write schedule");
+        // actions.json deliberately malformed
+        fs::write(data_dir.join("actions.json"), "{ not valid json").expect("write actions");
+        // emails.json deliberately missing
+
+        let report = load_day(today_dir);
+        assert!(report.overview.is_some());
+        assert!(!report.is_complete());
+        assert!(report
+            .failures
+            .iter()
+            .any(|(cat, _)| *cat == Category::Actions));
+        assert!(report
+            .failures
+            .iter()
+            .any(|(cat, err)| *cat == Category::Emails && matches!(err, LoaderError::FileMissing)));
+    }
+
+    #[test]
+    fn test_load_predicate_json_defaults_when_missing() {
+        let dir = tempdir().expect("tempdir");
+        let predicate = load_predicate_json(&dir.path().join("missing-view.json")).expect("load");
+        assert!(matches!(predicate, Predicate::AllOf(ref preds) if preds.is_empty()));
+    }
 }
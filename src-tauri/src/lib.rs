@@ -12,10 +12,13 @@ mod calendar_merge;
 mod capture;
 pub mod clay;
 mod commands;
+pub mod github;
 pub mod linear;
 pub mod db;
 mod db_backup;
+mod delivery_spool;
 mod devtools;
+mod edit_spool;
 pub mod embeddings;
 pub mod entity;
 pub mod entity_intel;
@@ -46,12 +49,15 @@ mod pty;
 pub mod granola;
 pub mod quill;
 pub mod queries;
+mod retention;
 mod risk_briefing;
 mod scheduler;
 pub mod signals;
+mod snapshot;
 pub mod state;
 pub mod types;
 pub mod util;
+pub mod version;
 mod watcher;
 mod workflow;
 
@@ -436,6 +442,22 @@ pub fn run() {
             // I76: Database Backup & Rebuild
             commands::backup_database,
             commands::rebuild_database,
+            // Transcript retention & archival
+            commands::prune_transcripts,
+            commands::get_retention_settings,
+            commands::set_retention_settings,
+            // Saved views (predicate-filtered day data)
+            commands::get_day_filtered,
+            // I197: Snapshot capture & restore
+            commands::capture_day_snapshot,
+            commands::restore_day_snapshot,
+            // I197: Delivery spool
+            commands::enqueue_briefing_delivery,
+            commands::process_pending_deliveries,
+            commands::get_delivery_status,
+            // I196: Local edit spool
+            commands::get_day_with_edits,
+            commands::record_field_edit,
             // I148: Hygiene
             commands::get_hygiene_report,
             commands::get_intelligence_hygiene_status,
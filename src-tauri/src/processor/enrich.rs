@@ -13,6 +13,7 @@ use crate::types::AiModelConfig;
 use crate::util::wrap_user_data;
 
 use super::classifier::Classification;
+use super::metadata::{self, ActionItem};
 use super::router::{move_file, resolve_destination};
 
 /// Timeout for AI processing per file (2 minutes)
@@ -449,6 +450,10 @@ pub struct ParsedEnrichment {
     /// Strategic TAM-perspective analysis from transcript prompt.
     pub analysis: Option<String>,
     pub actions_text: Option<String>,
+    /// Structured form of `actions_text` — priority (including `P0`), owner,
+    /// and due date parsed into typed fields rather than left for every
+    /// downstream reader to re-extract (chunk199-4).
+    pub actions: Vec<ActionItem>,
     pub wins: Vec<String>,
     pub risks: Vec<String>,
     pub decisions: Vec<String>,
@@ -565,6 +570,11 @@ pub fn parse_enrichment_response(output: &str) -> ParsedEnrichment {
     risks.truncate(20);
     decisions.truncate(20);
 
+    let actions = actions_text
+        .as_deref()
+        .map(metadata::parse_action_items)
+        .unwrap_or_default();
+
     ParsedEnrichment {
         file_type,
         account,
@@ -573,6 +583,7 @@ pub fn parse_enrichment_response(output: &str) -> ParsedEnrichment {
         discussion,
         analysis,
         actions_text,
+        actions,
         wins,
         risks,
         decisions,
@@ -588,8 +599,6 @@ pub fn extract_actions_from_ai(
     db: &ActionDb,
     account_fallback: Option<&str>,
 ) {
-    use super::metadata;
-
     let now = Utc::now().to_rfc3339();
     let mut count = 0;
     let max_actions = 50; // I296: cap parsed actions
@@ -663,6 +672,7 @@ pub fn extract_actions_from_ai(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::processor::metadata::Priority;
 
     #[test]
     fn test_parse_enrichment_response_with_wins_and_risks() {
@@ -687,6 +697,10 @@ END_RISKS";
         assert_eq!(parsed.file_type, "account_update");
         assert_eq!(parsed.account, Some("Acme Corp".to_string()));
         assert!(parsed.actions_text.is_some());
+        assert_eq!(parsed.actions.len(), 1);
+        assert_eq!(parsed.actions[0].priority, Priority::P2);
+        assert_eq!(parsed.actions[0].owner.as_deref(), Some("Acme"));
+        assert_eq!(parsed.actions[0].text, "Follow up on renewal");
         assert_eq!(parsed.wins.len(), 2);
         assert_eq!(parsed.wins[0], "Expanded deployment to 3 new teams");
         assert_eq!(parsed.wins[1], "NPS score increased to 9");
@@ -694,6 +708,39 @@ END_RISKS";
         assert_eq!(parsed.risks[0], "Budget freeze announced for Q2");
     }
 
+    #[test]
+    fn test_parse_enrichment_response_actions_capture_owner_marker_and_due_date() {
+        let output = "\
+FILE_TYPE: account_update
+ACCOUNT: Acme Corp
+MEETING: NONE
+SUMMARY: Quarterly review notes
+ACTIONS:
+- P0 Submit report (owner: Jane Doe) due: 2026-01-31
+- Ping finance
+END_ACTIONS";
+
+        let parsed = parse_enrichment_response(output);
+
+        assert_eq!(parsed.actions.len(), 2);
+
+        let first = &parsed.actions[0];
+        assert_eq!(first.priority, Priority::P0);
+        assert_eq!(first.owner.as_deref(), Some("Jane Doe"));
+        assert_eq!(
+            first.due,
+            Some(chrono::NaiveDate::from_ymd_opt(2026, 1, 31).unwrap())
+        );
+        assert_eq!(first.text, "Submit report");
+
+        // Unrecognized line round-trips as Priority::Unset with full text kept.
+        let second = &parsed.actions[1];
+        assert_eq!(second.priority, Priority::Unset);
+        assert!(second.owner.is_none());
+        assert!(second.due.is_none());
+        assert_eq!(second.text, "Ping finance");
+    }
+
     #[test]
     fn test_parse_enrichment_response_empty_wins_risks() {
         let output = "\
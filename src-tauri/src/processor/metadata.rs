@@ -3,9 +3,15 @@
 //! Extracts priority (`P1`/`P2`/`P3`), account (`@Name`), due date (`due: YYYY-MM-DD`),
 //! context (`#tag`), and waiting/blocked status from action text.
 //! Returns a clean title with mechanical tokens stripped.
+//!
+//! [`ActionItem`]/[`parse_action_items`] (chunk199-4) parse a whole `ACTIONS:`
+//! block into structured items rather than leaving each line as opaque text —
+//! priority (now including `P0`), owner, and due date become typed fields
+//! instead of something every downstream reader has to re-extract.
 
 use std::sync::OnceLock;
 
+use chrono::NaiveDate;
 use regex::Regex;
 
 /// Parsed metadata from an action line.
@@ -101,6 +107,137 @@ pub fn parse_action_metadata(text: &str) -> ActionMetadata {
     meta
 }
 
+/// Priority tier for a structured [`ActionItem`]. `Unset` preserves a line
+/// that carried no recognizable priority token rather than guessing — a
+/// default like `P2` is a downstream policy decision (see
+/// `crate::github::priority_label`), not something the parser should assume.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Priority {
+    P0,
+    P1,
+    P2,
+    P3,
+    Unset,
+}
+
+impl Priority {
+    fn from_token(token: &str) -> Self {
+        match token.to_uppercase().as_str() {
+            "P0" => Priority::P0,
+            "P1" => Priority::P1,
+            "P2" => Priority::P2,
+            "P3" => Priority::P3,
+            _ => Priority::Unset,
+        }
+    }
+}
+
+impl Priority {
+    /// Render as the `P0`..`P3` token `DbAction::priority` and `github::priority_label`
+    /// expect; `None` for `Unset` so callers can apply their own default (see
+    /// `crate::github::priority_label`) instead of baking one in here.
+    pub fn as_str(&self) -> Option<&'static str> {
+        match self {
+            Priority::P0 => Some("P0"),
+            Priority::P1 => Some("P1"),
+            Priority::P2 => Some("P2"),
+            Priority::P3 => Some("P3"),
+            Priority::Unset => None,
+        }
+    }
+}
+
+/// A single structured action item parsed from an `ACTIONS:` block line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActionItem {
+    pub priority: Priority,
+    pub owner: Option<String>,
+    pub due: Option<NaiveDate>,
+    /// Context tag from `#tag`, mirrors `ActionMetadata::context`.
+    pub context: Option<String>,
+    /// Whether waiting/blocked/pending keywords were found (NOT stripped from `text`).
+    pub is_waiting: bool,
+    pub text: String,
+}
+
+fn re_priority_p0() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"(?i)\b(P[0123])\b").unwrap())
+}
+
+fn re_owner_marker() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"(?i)\(owner:\s*([^)]+)\)").unwrap())
+}
+
+/// Parse each `- ` line of an `ACTIONS:` block into a structured
+/// [`ActionItem`]. Lines with no recognizable priority token round-trip as
+/// `Priority::Unset` with their full text preserved, so nothing is dropped.
+pub fn parse_action_items(actions_text: &str) -> Vec<ActionItem> {
+    actions_text
+        .lines()
+        .filter_map(|line| {
+            let trimmed = line.trim();
+            let raw = trimmed
+                .strip_prefix("- [ ] ")
+                .or_else(|| trimmed.strip_prefix("- "))?
+                .trim();
+            if raw.is_empty() {
+                None
+            } else {
+                Some(parse_action_item(raw))
+            }
+        })
+        .collect()
+}
+
+/// Parse a single action line (no leading `- `) into an [`ActionItem`].
+pub fn parse_action_item(raw: &str) -> ActionItem {
+    let priority = re_priority_p0()
+        .captures(raw)
+        .map(|caps| Priority::from_token(&caps[1]))
+        .unwrap_or(Priority::Unset);
+
+    // Owner: an explicit `(owner: ...)` marker wins over the shorthand `@name`.
+    let owner = re_owner_marker()
+        .captures(raw)
+        .and_then(|caps| caps.get(1))
+        .map(|m| m.as_str().trim().to_string())
+        .or_else(|| {
+            re_account()
+                .captures(raw)
+                .map(|caps| caps[1].to_string())
+        });
+
+    let due = re_due_date()
+        .captures(raw)
+        .and_then(|caps| NaiveDate::parse_from_str(&caps[1], "%Y-%m-%d").ok());
+
+    let context = re_context()
+        .captures(raw)
+        .and_then(|caps| caps.get(1).or_else(|| caps.get(2)))
+        .map(|m| m.as_str().to_string());
+
+    let is_waiting = re_waiting().is_match(raw);
+
+    let mut clean = raw.to_string();
+    clean = re_priority_p0().replace_all(&clean, "").to_string();
+    clean = re_owner_marker().replace_all(&clean, "").to_string();
+    clean = re_account().replace_all(&clean, "").to_string();
+    clean = re_due_date().replace_all(&clean, "").to_string();
+    clean = re_context().replace_all(&clean, "").to_string();
+    let text = clean.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    ActionItem {
+        priority,
+        owner,
+        due,
+        context,
+        is_waiting,
+        text,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -232,4 +369,70 @@ mod tests {
         assert_eq!(m.due_date.as_deref(), Some("2026-03-15"));
         assert_eq!(m.clean_title, "Submit report");
     }
+
+    #[test]
+    fn parse_action_items_extracts_priority_owner_and_due_date() {
+        let items = parse_action_items("- P1 @Acme Follow up on renewal due: 2026-03-15");
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].priority, Priority::P1);
+        assert_eq!(items[0].owner.as_deref(), Some("Acme"));
+        assert_eq!(
+            items[0].due,
+            Some(NaiveDate::from_ymd_opt(2026, 3, 15).unwrap())
+        );
+        assert_eq!(items[0].text, "Follow up on renewal");
+    }
+
+    #[test]
+    fn parse_action_items_recognizes_p0() {
+        let items = parse_action_items("- P0 Outage: roll back the bad deploy");
+        assert_eq!(items[0].priority, Priority::P0);
+        assert_eq!(items[0].text, "Outage: roll back the bad deploy");
+    }
+
+    #[test]
+    fn parse_action_items_recognizes_owner_marker() {
+        let items = parse_action_items("- Review contract (owner: Jane Doe)");
+        assert_eq!(items[0].owner.as_deref(), Some("Jane Doe"));
+        assert_eq!(items[0].text, "Review contract");
+    }
+
+    #[test]
+    fn parse_action_items_round_trips_unrecognized_lines_as_unset() {
+        let items = parse_action_items("- Send weekly update email");
+        assert_eq!(items[0].priority, Priority::Unset);
+        assert!(items[0].owner.is_none());
+        assert!(items[0].due.is_none());
+        assert_eq!(items[0].text, "Send weekly update email");
+    }
+
+    #[test]
+    fn parse_action_items_handles_multiple_lines_and_checkbox_prefix() {
+        let items = parse_action_items(
+            "- [ ] P2 @Beta Submit report due: 2026-01-31\n- Ping finance",
+        );
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].priority, Priority::P2);
+        assert_eq!(items[0].owner.as_deref(), Some("Beta"));
+        assert_eq!(items[1].priority, Priority::Unset);
+        assert_eq!(items[1].text, "Ping finance");
+    }
+
+    #[test]
+    fn parse_action_items_extracts_context_and_waiting() {
+        let items = parse_action_items("- Waiting on John #billing P1");
+        assert_eq!(items[0].priority, Priority::P1);
+        assert_eq!(items[0].context.as_deref(), Some("billing"));
+        assert!(items[0].is_waiting);
+        assert_eq!(items[0].text, "Waiting on John");
+    }
+
+    #[test]
+    fn priority_as_str_round_trips_p0_through_p3_and_none_for_unset() {
+        assert_eq!(Priority::P0.as_str(), Some("P0"));
+        assert_eq!(Priority::P1.as_str(), Some("P1"));
+        assert_eq!(Priority::P2.as_str(), Some("P2"));
+        assert_eq!(Priority::P3.as_str(), Some("P3"));
+        assert_eq!(Priority::Unset.as_str(), None);
+    }
 }
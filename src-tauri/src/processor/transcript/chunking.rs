@@ -0,0 +1,115 @@
+//! Splits an over-budget transcript into chunks for map-reduce extraction
+//! (chunk198-3 follow-up to I44).
+//!
+//! Recording tools don't cap how long a call runs, but the AI context window
+//! does. Rather than truncating the middle of a long call out of the prompt,
+//! `process_transcript` splits it into sequential chunks sized to fit the
+//! window — always on speaker-turn boundaries, never mid-turn — with each
+//! chunk after the first repeating the previous chunk's last turn or two so
+//! the model has continuity across the split.
+
+use super::format::{NormalizedTranscript, TranscriptTurn};
+
+/// Turns repeated at the start of each chunk after the first, for continuity.
+const OVERLAP_TURNS: usize = 2;
+
+/// Approximate rendered length of one turn, matching how
+/// [`NormalizedTranscript::to_prose`] renders a "Speaker: text" line.
+fn turn_len(turn: &TranscriptTurn) -> usize {
+    turn.text.len() + turn.speaker.as_ref().map_or(0, |s| s.len() + 2)
+}
+
+/// Split `normalized` into sequential chunks whose rendered prose each fit
+/// within `max_chars`. A transcript that already fits is returned as a single
+/// chunk — callers should treat that as the single-pass case.
+pub fn chunk_transcript(
+    normalized: &NormalizedTranscript,
+    max_chars: usize,
+) -> Vec<NormalizedTranscript> {
+    if normalized.to_prose().len() <= max_chars {
+        return vec![normalized.clone()];
+    }
+
+    let mut chunks: Vec<NormalizedTranscript> = Vec::new();
+    let mut current: Vec<TranscriptTurn> = Vec::new();
+    let mut current_len = 0usize;
+
+    for turn in &normalized.turns {
+        let len = turn_len(turn);
+        if !current.is_empty() && current_len + len > max_chars {
+            chunks.push(NormalizedTranscript {
+                turns: std::mem::take(&mut current),
+            });
+
+            let prev_turns = &chunks.last().unwrap().turns;
+            let overlap_start = prev_turns.len().saturating_sub(OVERLAP_TURNS);
+            current = prev_turns[overlap_start..].to_vec();
+            current_len = current.iter().map(turn_len).sum();
+        }
+
+        current_len += len;
+        current.push(turn.clone());
+    }
+
+    if !current.is_empty() {
+        chunks.push(NormalizedTranscript { turns: current });
+    }
+
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn turn(speaker: &str, text: &str) -> TranscriptTurn {
+        TranscriptTurn {
+            speaker: Some(speaker.to_string()),
+            text: text.to_string(),
+            timestamp: None,
+        }
+    }
+
+    #[test]
+    fn test_chunk_transcript_fits_in_one_chunk() {
+        let normalized = NormalizedTranscript {
+            turns: vec![turn("Alice", "Hello"), turn("Bob", "Hi")],
+        };
+        let chunks = chunk_transcript(&normalized, 1_000);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].turns.len(), 2);
+    }
+
+    #[test]
+    fn test_chunk_transcript_splits_on_turn_boundaries_with_overlap() {
+        let turns: Vec<TranscriptTurn> = (0..6)
+            .map(|i| turn("Alice", &format!("turn-{}-{}", i, "x".repeat(30))))
+            .collect();
+        let normalized = NormalizedTranscript { turns };
+
+        let chunks = chunk_transcript(&normalized, 100);
+        assert!(chunks.len() > 1, "expected a split, got {} chunk(s)", chunks.len());
+
+        // Every turn that appears in a chunk is a whole, unmodified turn from
+        // the source — never split mid-turn.
+        for chunk in &chunks {
+            for t in &chunk.turns {
+                assert!(normalized.turns.iter().any(|original| original.text == t.text));
+            }
+        }
+
+        // The second chunk repeats the first chunk's last turn for continuity.
+        let last_of_first = chunks[0].turns.last().unwrap().text.clone();
+        assert!(chunks[1].turns.iter().any(|t| t.text == last_of_first));
+    }
+
+    #[test]
+    fn test_chunk_transcript_keeps_oversized_single_turn_whole() {
+        let normalized = NormalizedTranscript {
+            turns: vec![turn("Alice", &"x".repeat(200))],
+        };
+        let chunks = chunk_transcript(&normalized, 50);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].turns[0].text.len(), 200);
+    }
+}
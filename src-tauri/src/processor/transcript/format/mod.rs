@@ -0,0 +1,189 @@
+//! Per-format transcript parsers (I44 follow-up).
+//!
+//! Recording tools export transcripts in wildly different shapes — WebVTT cue
+//! lists, SRT subtitles, Zoom's VTT-with-inline-speaker variant, Otter.ai's
+//! name-then-timestamp blocks, or just plain speaker-labeled prose. Sending any
+//! of these straight to the model wastes the prompt window on cue numbers and
+//! `-->` timestamp lines. Each submodule implements [`TranscriptFormat`] for one
+//! export shape and normalizes it to a flat list of [`TranscriptTurn`]s that
+//! [`super::process_transcript`] renders back to plain prose before prompting.
+
+pub mod otter;
+pub mod plain;
+pub mod srt;
+pub mod vtt;
+pub mod zoom;
+
+use std::path::Path;
+use std::time::Duration;
+
+/// One speaker turn normalized out of a raw transcript export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranscriptTurn {
+    pub speaker: Option<String>,
+    pub text: String,
+    /// Cue start time, when the source format carries one.
+    pub timestamp: Option<Duration>,
+}
+
+/// A transcript normalized into speaker turns, independent of its source format.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NormalizedTranscript {
+    pub turns: Vec<TranscriptTurn>,
+}
+
+impl NormalizedTranscript {
+    /// Render turns back to plain speaker-labeled prose for prompting, dropping
+    /// timestamps (kept on the turns themselves for later features).
+    pub fn to_prose(&self) -> String {
+        self.turns
+            .iter()
+            .map(|turn| match &turn.speaker {
+                Some(speaker) => format!("{}: {}", speaker, turn.text),
+                None => turn.text.clone(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// A parser for one transcript export format.
+pub trait TranscriptFormat {
+    /// Parse raw file content into normalized speaker turns.
+    fn parse(&self, content: &str) -> NormalizedTranscript;
+}
+
+/// Transcript export format, as picked by [`sniff_format`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetectedFormat {
+    Vtt,
+    Srt,
+    Zoom,
+    Otter,
+    Plain,
+}
+
+impl DetectedFormat {
+    /// The [`TranscriptFormat`] parser for this detected format.
+    pub fn parser(self) -> Box<dyn TranscriptFormat> {
+        match self {
+            DetectedFormat::Vtt => Box::new(vtt::VttFormat),
+            DetectedFormat::Srt => Box::new(srt::SrtFormat),
+            DetectedFormat::Zoom => Box::new(zoom::ZoomFormat),
+            DetectedFormat::Otter => Box::new(otter::OtterFormat),
+            DetectedFormat::Plain => Box::new(plain::PlainFormat),
+        }
+    }
+}
+
+/// Sniff a transcript's export format from its filename extension and content
+/// shape. `.srt` files are unambiguous. `.vtt` files are WebVTT, but Zoom's cloud
+/// recording export is also WebVTT with the speaker inlined in the cue text
+/// ("Alice: Hello") rather than a `<v Alice>` tag, so those are distinguished by
+/// content. Otter.ai's plain-text export is recognized by its
+/// `Speaker  MM:SS` header line above each block. Anything else falls back to
+/// [`DetectedFormat::Plain`].
+pub fn sniff_format(filename: &str, content: &str) -> DetectedFormat {
+    let ext = Path::new(filename)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+
+    match ext.as_str() {
+        "srt" => return DetectedFormat::Srt,
+        "vtt" => {
+            return if zoom::looks_like_zoom_vtt(content) {
+                DetectedFormat::Zoom
+            } else {
+                DetectedFormat::Vtt
+            };
+        }
+        _ => {}
+    }
+
+    if otter::looks_like_otter(content) {
+        return DetectedFormat::Otter;
+    }
+
+    DetectedFormat::Plain
+}
+
+/// Parse a WebVTT/SRT cue timestamp (`HH:MM:SS.mmm` or `HH:MM:SS,mmm`, the
+/// hours component is optional) into a [`Duration`]. Returns `None` on anything
+/// that doesn't parse, so callers can fall back to an unstamped turn.
+pub(crate) fn parse_timestamp(raw: &str) -> Option<Duration> {
+    let raw = raw.trim().replace(',', ".");
+    let (time_part, millis_part) = raw.split_once('.').unwrap_or((raw.as_str(), "0"));
+    let millis: u64 = millis_part
+        .get(..3.min(millis_part.len()))
+        .unwrap_or("0")
+        .parse()
+        .ok()?;
+
+    let parts: Vec<&str> = time_part.split(':').collect();
+    let (hours, minutes, seconds) = match parts.as_slice() {
+        [h, m, s] => (h.parse().ok()?, m.parse::<u64>().ok()?, s.parse::<u64>().ok()?),
+        [m, s] => (0u64, m.parse().ok()?, s.parse::<u64>().ok()?),
+        _ => return None,
+    };
+
+    Some(Duration::from_millis(
+        (hours * 3600 + minutes * 60 + seconds) * 1000 + millis,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sniff_format_by_extension() {
+        assert_eq!(sniff_format("call.srt", ""), DetectedFormat::Srt);
+        assert_eq!(
+            sniff_format(
+                "call.vtt",
+                "WEBVTT\n\n1\n00:00:00.000 --> 00:00:02.000\n<v Alice>Hi</v>\n"
+            ),
+            DetectedFormat::Vtt
+        );
+    }
+
+    #[test]
+    fn test_sniff_format_zoom_vtt_by_content() {
+        let content = "WEBVTT\n\n1\n00:00:00.000 --> 00:00:02.000\nAlice: Hi everyone\n";
+        assert_eq!(sniff_format("audio_transcript.vtt", content), DetectedFormat::Zoom);
+    }
+
+    #[test]
+    fn test_sniff_format_falls_back_to_plain() {
+        assert_eq!(sniff_format("notes.txt", "Just some prose."), DetectedFormat::Plain);
+    }
+
+    #[test]
+    fn test_parse_timestamp_vtt_and_srt_styles() {
+        assert_eq!(parse_timestamp("00:01:23.456"), Some(Duration::from_millis(83456)));
+        assert_eq!(parse_timestamp("00:01:23,456"), Some(Duration::from_millis(83456)));
+        assert_eq!(parse_timestamp("01:23"), Some(Duration::from_millis(83000)));
+        assert_eq!(parse_timestamp("not a timestamp"), None);
+    }
+
+    #[test]
+    fn test_normalized_transcript_to_prose() {
+        let normalized = NormalizedTranscript {
+            turns: vec![
+                TranscriptTurn {
+                    speaker: Some("Alice".to_string()),
+                    text: "Hello".to_string(),
+                    timestamp: Some(Duration::from_secs(1)),
+                },
+                TranscriptTurn {
+                    speaker: None,
+                    text: "unattributed aside".to_string(),
+                    timestamp: None,
+                },
+            ],
+        };
+        assert_eq!(normalized.to_prose(), "Alice: Hello\nunattributed aside");
+    }
+}
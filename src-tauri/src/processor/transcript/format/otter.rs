@@ -0,0 +1,85 @@
+//! Otter.ai transcript export parser.
+//!
+//! Otter's plain-text export is a series of blocks, each opening with a header
+//! line of `Speaker Name  MM:SS` (or `H:MM:SS`), followed by one or more lines
+//! of that speaker's text, separated by a blank line from the next block.
+
+use regex::Regex;
+
+use super::{parse_timestamp, NormalizedTranscript, TranscriptFormat, TranscriptTurn};
+
+pub struct OtterFormat;
+
+fn header_re() -> Regex {
+    Regex::new(r"^([A-Za-z][A-Za-z .'\-]{0,40})\s{2,}(\d{1,2}:\d{2}(?::\d{2})?)$").unwrap()
+}
+
+/// True when `content` opens enough of its blocks with an Otter-style
+/// `Speaker  MM:SS` header line to be confident this is an Otter export.
+pub fn looks_like_otter(content: &str) -> bool {
+    let re = header_re();
+    let mut headers = 0;
+    let mut blocks = 0;
+    for block in content.replace("\r\n", "\n").split("\n\n") {
+        let Some(first) = block.lines().find(|l| !l.trim().is_empty()) else { continue };
+        blocks += 1;
+        if re.is_match(first.trim()) {
+            headers += 1;
+        }
+    }
+    blocks > 0 && headers as f64 / blocks as f64 > 0.5
+}
+
+impl TranscriptFormat for OtterFormat {
+    fn parse(&self, content: &str) -> NormalizedTranscript {
+        let re = header_re();
+        let mut turns = Vec::new();
+
+        for block in content.replace("\r\n", "\n").split("\n\n") {
+            let mut lines = block.lines().filter(|l| !l.trim().is_empty());
+            let Some(first) = lines.next() else { continue };
+
+            let Some(caps) = re.captures(first.trim()) else { continue };
+            let speaker = caps[1].trim().to_string();
+            let timestamp = parse_timestamp(&caps[2]);
+
+            let text = lines.collect::<Vec<_>>().join(" ");
+            if text.is_empty() {
+                continue;
+            }
+
+            turns.push(TranscriptTurn {
+                speaker: Some(speaker),
+                text,
+                timestamp,
+            });
+        }
+
+        NormalizedTranscript { turns }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_looks_like_otter() {
+        let content = "Alice Smith  00:00\nHello everyone, thanks for joining.\n\nBob Jones  00:05\nThanks for having me.\n";
+        assert!(looks_like_otter(content));
+        assert!(!looks_like_otter("Just a plain paragraph of prose with no headers at all."));
+    }
+
+    #[test]
+    fn test_parse_otter_transcript() {
+        let content = "Alice Smith  00:00\nHello everyone, thanks for joining.\n\nBob Jones  00:05\nThanks for having me.\n";
+        let parsed = OtterFormat.parse(content);
+
+        assert_eq!(parsed.turns.len(), 2);
+        assert_eq!(parsed.turns[0].speaker, Some("Alice Smith".to_string()));
+        assert_eq!(parsed.turns[0].text, "Hello everyone, thanks for joining.");
+        assert_eq!(parsed.turns[0].timestamp, Some(Duration::from_secs(0)));
+        assert_eq!(parsed.turns[1].speaker, Some("Bob Jones".to_string()));
+    }
+}
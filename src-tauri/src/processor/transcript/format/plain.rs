@@ -0,0 +1,95 @@
+//! Fallback parser for plain speaker-labeled prose (or anything else).
+//!
+//! Recognizes `Name: text` lines the same way `processor::enrich`'s transcript
+//! heuristic does. A line that doesn't look like a speaker label is
+//! appended to the current turn's text (or starts an unattributed turn, if
+//! there's no current speaker yet) rather than discarded.
+
+use super::{NormalizedTranscript, TranscriptFormat, TranscriptTurn};
+
+pub struct PlainFormat;
+
+/// Split a leading `Name: ` speaker label off a line, using the same shape
+/// rules as the inbox transcript-detection heuristic (short, capitalized,
+/// alphabetic prefix).
+fn split_speaker_label(line: &str) -> Option<(String, String)> {
+    if line.trim_start().starts_with('#') {
+        return None;
+    }
+    let i = line.find(':')?;
+    let prefix = &line[..i];
+    let word_count = prefix.split_whitespace().count();
+    if i > 0
+        && i < 25
+        && word_count <= 3
+        && prefix.chars().all(|c| c.is_alphabetic() || c == ' ' || c == '.')
+        && prefix.chars().next()?.is_uppercase()
+    {
+        Some((prefix.trim().to_string(), line[i + 1..].trim().to_string()))
+    } else {
+        None
+    }
+}
+
+impl TranscriptFormat for PlainFormat {
+    fn parse(&self, content: &str) -> NormalizedTranscript {
+        let mut turns: Vec<TranscriptTurn> = Vec::new();
+
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some((speaker, text)) = split_speaker_label(line) {
+                if text.is_empty() {
+                    continue;
+                }
+                turns.push(TranscriptTurn {
+                    speaker: Some(speaker),
+                    text,
+                    timestamp: None,
+                });
+            } else if let Some(last) = turns.last_mut() {
+                last.text.push('\n');
+                last.text.push_str(line);
+            } else {
+                turns.push(TranscriptTurn {
+                    speaker: None,
+                    text: line.to_string(),
+                    timestamp: None,
+                });
+            }
+        }
+
+        NormalizedTranscript { turns }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_plain_speaker_labeled_prose() {
+        let content = "Alice: Hi everyone.\nBob: Hello Alice.\nAlice: Let's get started.";
+        let parsed = PlainFormat.parse(content);
+
+        assert_eq!(parsed.turns.len(), 3);
+        assert_eq!(parsed.turns[0].speaker, Some("Alice".to_string()));
+        assert_eq!(parsed.turns[1].speaker, Some("Bob".to_string()));
+    }
+
+    #[test]
+    fn test_parse_plain_prose_without_speakers() {
+        let content = "Just some unstructured notes.\nA second line of notes.";
+        let parsed = PlainFormat.parse(content);
+
+        assert_eq!(parsed.turns.len(), 1);
+        assert_eq!(parsed.turns[0].speaker, None);
+        assert_eq!(
+            parsed.turns[0].text,
+            "Just some unstructured notes.\nA second line of notes."
+        );
+    }
+}
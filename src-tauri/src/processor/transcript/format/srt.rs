@@ -0,0 +1,96 @@
+//! SRT subtitle parser.
+//!
+//! SRT blocks always open with a numeric cue index, followed by a
+//! `start --> end` line using comma-separated milliseconds, then one or more
+//! text lines. SRT has no native speaker field, so exports that prefix dialogue
+//! with `Name: ` are unwrapped into a speaker; everything else stays
+//! unattributed.
+
+use super::{parse_timestamp, NormalizedTranscript, TranscriptFormat, TranscriptTurn};
+
+pub struct SrtFormat;
+
+/// Split a leading `Name: ` prefix off a text line, if present.
+fn split_inline_speaker(line: &str) -> (Option<String>, String) {
+    if let Some(colon) = line.find(':') {
+        let prefix = &line[..colon];
+        let word_count = prefix.split_whitespace().count();
+        if !prefix.is_empty()
+            && word_count <= 3
+            && prefix.chars().all(|c| c.is_alphanumeric() || c == ' ' || c == '.')
+            && prefix.chars().next().is_some_and(|c| c.is_uppercase())
+        {
+            return (Some(prefix.trim().to_string()), line[colon + 1..].trim().to_string());
+        }
+    }
+    (None, line.trim().to_string())
+}
+
+impl TranscriptFormat for SrtFormat {
+    fn parse(&self, content: &str) -> NormalizedTranscript {
+        let mut turns = Vec::new();
+
+        for block in content.replace("\r\n", "\n").split("\n\n") {
+            let mut lines = block.lines().filter(|l| !l.trim().is_empty());
+
+            // Cue index line — ignored, but its presence confirms block shape.
+            let Some(first) = lines.next() else { continue };
+            let timestamp_line = if first.trim().parse::<u32>().is_ok() {
+                match lines.next() {
+                    Some(l) => l,
+                    None => continue,
+                }
+            } else {
+                first
+            };
+            if !timestamp_line.contains("-->") {
+                continue;
+            }
+
+            let timestamp = timestamp_line.split("-->").next().and_then(parse_timestamp);
+
+            let text_lines: Vec<&str> = lines.collect();
+            if text_lines.is_empty() {
+                continue;
+            }
+
+            let (speaker, first_text) = split_inline_speaker(text_lines[0]);
+            let mut text = first_text;
+            for extra in &text_lines[1..] {
+                text.push(' ');
+                text.push_str(extra.trim());
+            }
+
+            turns.push(TranscriptTurn { speaker, text, timestamp });
+        }
+
+        NormalizedTranscript { turns }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_parse_srt_with_inline_speaker() {
+        let content = "1\n00:00:01,000 --> 00:00:03,000\nAlice: Hello everyone.\n\n2\n00:00:04,000 --> 00:00:06,000\nBob: Hi Alice.\n";
+        let parsed = SrtFormat.parse(content);
+
+        assert_eq!(parsed.turns.len(), 2);
+        assert_eq!(parsed.turns[0].speaker, Some("Alice".to_string()));
+        assert_eq!(parsed.turns[0].text, "Hello everyone.");
+        assert_eq!(parsed.turns[0].timestamp, Some(Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn test_parse_srt_without_speaker() {
+        let content = "1\n00:00:00,000 --> 00:00:02,000\nWelcome to the call.\n";
+        let parsed = SrtFormat.parse(content);
+
+        assert_eq!(parsed.turns.len(), 1);
+        assert_eq!(parsed.turns[0].speaker, None);
+        assert_eq!(parsed.turns[0].text, "Welcome to the call.");
+    }
+}
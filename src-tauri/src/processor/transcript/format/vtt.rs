@@ -0,0 +1,108 @@
+//! WebVTT caption parser.
+//!
+//! Handles the standard shape: an optional `WEBVTT` header, `NOTE` blocks to
+//! skip, cue blocks made up of an optional numeric identifier line, a
+//! `start --> end` timestamp line, then one or more text lines. Speaker
+//! attribution comes from an inline `<v Speaker Name>` voice tag when present;
+//! cues without one are kept unattributed.
+
+use super::{parse_timestamp, NormalizedTranscript, TranscriptFormat, TranscriptTurn};
+
+pub struct VttFormat;
+
+/// Strip a leading `<v Name>` voice tag (and any trailing `</v>`), returning
+/// the speaker name (if present) and the remaining text.
+fn split_voice_tag(line: &str) -> (Option<String>, String) {
+    let line = line.trim();
+    let voice_tag = line
+        .strip_prefix("<v ")
+        .or_else(|| line.strip_prefix("<v."))
+        .and_then(|rest| rest.find('>').map(|end| (rest, end)));
+    if let Some((rest, end)) = voice_tag {
+        let speaker = rest[..end].trim().to_string();
+        let text = rest[end + 1..].trim_end_matches("</v>").trim();
+        return (Some(speaker), text.to_string());
+    }
+    (None, line.to_string())
+}
+
+impl TranscriptFormat for VttFormat {
+    fn parse(&self, content: &str) -> NormalizedTranscript {
+        let mut turns = Vec::new();
+
+        for block in content.replace("\r\n", "\n").split("\n\n") {
+            let mut lines = block.lines().filter(|l| !l.trim().is_empty());
+
+            let Some(first) = lines.next() else { continue };
+            if first.trim() == "WEBVTT" || first.trim_start().starts_with("NOTE") {
+                continue;
+            }
+
+            // An optional cue identifier precedes the timestamp line.
+            let timestamp_line = if first.contains("-->") {
+                first
+            } else {
+                match lines.next() {
+                    Some(l) if l.contains("-->") => l,
+                    _ => continue,
+                }
+            };
+
+            let timestamp = timestamp_line
+                .split("-->")
+                .next()
+                .and_then(parse_timestamp);
+
+            let text_lines: Vec<&str> = lines.collect();
+            if text_lines.is_empty() {
+                continue;
+            }
+
+            let (speaker, first_text) = split_voice_tag(text_lines[0]);
+            let mut text = first_text;
+            for extra in &text_lines[1..] {
+                let (_, extra_text) = split_voice_tag(extra);
+                if !extra_text.is_empty() {
+                    text.push(' ');
+                    text.push_str(&extra_text);
+                }
+            }
+
+            if text.is_empty() {
+                continue;
+            }
+
+            turns.push(TranscriptTurn { speaker, text, timestamp });
+        }
+
+        NormalizedTranscript { turns }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_parse_vtt_with_voice_tags() {
+        let content = "WEBVTT\n\n1\n00:00:01.000 --> 00:00:03.000\n<v Alice>Hello everyone.</v>\n\n2\n00:00:04.000 --> 00:00:06.000\n<v Bob>Hi Alice.</v>\n";
+        let parsed = VttFormat.parse(content);
+
+        assert_eq!(parsed.turns.len(), 2);
+        assert_eq!(parsed.turns[0].speaker, Some("Alice".to_string()));
+        assert_eq!(parsed.turns[0].text, "Hello everyone.");
+        assert_eq!(parsed.turns[0].timestamp, Some(Duration::from_secs(1)));
+        assert_eq!(parsed.turns[1].speaker, Some("Bob".to_string()));
+    }
+
+    #[test]
+    fn test_parse_vtt_without_speaker_tags() {
+        let content = "WEBVTT\n\n00:00:00.000 --> 00:00:02.000\nWelcome to the call.\n";
+        let parsed = VttFormat.parse(content);
+
+        assert_eq!(parsed.turns.len(), 1);
+        assert_eq!(parsed.turns[0].speaker, None);
+        assert_eq!(parsed.turns[0].text, "Welcome to the call.");
+    }
+}
@@ -0,0 +1,134 @@
+//! Zoom cloud-recording transcript parser.
+//!
+//! Zoom's cloud recording transcript is WebVTT-shaped (numeric cue index,
+//! `start --> end` timestamp line), but unlike standard WebVTT it inlines the
+//! speaker as plain `Name: text` in the cue body instead of a `<v>` voice tag.
+//! [`super::sniff_format`] tells the two apart via [`looks_like_zoom_vtt`].
+
+use super::{parse_timestamp, NormalizedTranscript, TranscriptFormat, TranscriptTurn};
+
+pub struct ZoomFormat;
+
+/// Split a leading `Name: ` prefix off a cue's text, as Zoom inlines it.
+fn split_inline_speaker(line: &str) -> (Option<String>, String) {
+    if let Some(colon) = line.find(':') {
+        let prefix = &line[..colon];
+        let word_count = prefix.split_whitespace().count();
+        if !prefix.is_empty()
+            && word_count <= 3
+            && prefix.chars().all(|c| c.is_alphanumeric() || c == ' ' || c == '.')
+            && prefix.chars().next().is_some_and(|c| c.is_uppercase())
+        {
+            return (Some(prefix.trim().to_string()), line[colon + 1..].trim().to_string());
+        }
+    }
+    (None, line.trim().to_string())
+}
+
+/// Pull the cue text lines out of one WebVTT-shaped block: an optional cue
+/// index line, then a `start --> end` timestamp line, then the body.
+fn cue_text_lines(block: &str) -> Option<Vec<&str>> {
+    let mut lines = block.lines().filter(|l| !l.trim().is_empty());
+    let first = lines.next()?;
+    if first.trim() == "WEBVTT" {
+        return None;
+    }
+
+    if !first.contains("-->") {
+        lines.next().filter(|l| l.contains("-->"))?;
+    }
+
+    let text_lines: Vec<&str> = lines.collect();
+    if text_lines.is_empty() {
+        None
+    } else {
+        Some(text_lines)
+    }
+}
+
+/// A `.vtt` file is Zoom's variant (rather than standard WebVTT) when it has no
+/// `<v ` voice tags but its cue bodies are inline-speaker-labeled.
+pub fn looks_like_zoom_vtt(content: &str) -> bool {
+    if content.contains("<v ") {
+        return false;
+    }
+
+    let mut labeled = 0;
+    let mut total = 0;
+    for block in content.replace("\r\n", "\n").split("\n\n") {
+        let Some(text_lines) = cue_text_lines(block) else { continue };
+        total += 1;
+        if split_inline_speaker(text_lines[0]).0.is_some() {
+            labeled += 1;
+        }
+    }
+
+    total > 0 && labeled as f64 / total as f64 > 0.5
+}
+
+impl TranscriptFormat for ZoomFormat {
+    fn parse(&self, content: &str) -> NormalizedTranscript {
+        let mut turns = Vec::new();
+
+        for block in content.replace("\r\n", "\n").split("\n\n") {
+            let mut lines = block.lines().filter(|l| !l.trim().is_empty());
+
+            let Some(first) = lines.next() else { continue };
+            if first.trim() == "WEBVTT" {
+                continue;
+            }
+
+            let timestamp_line = if first.contains("-->") {
+                first
+            } else {
+                match lines.next() {
+                    Some(l) if l.contains("-->") => l,
+                    _ => continue,
+                }
+            };
+            let timestamp = timestamp_line.split("-->").next().and_then(parse_timestamp);
+
+            let text_lines: Vec<&str> = lines.collect();
+            if text_lines.is_empty() {
+                continue;
+            }
+
+            let (speaker, first_text) = split_inline_speaker(text_lines[0]);
+            let mut text = first_text;
+            for extra in &text_lines[1..] {
+                text.push(' ');
+                text.push_str(extra.trim());
+            }
+
+            turns.push(TranscriptTurn { speaker, text, timestamp });
+        }
+
+        NormalizedTranscript { turns }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_looks_like_zoom_vtt() {
+        let zoom = "WEBVTT\n\n1\n00:00:01.000 --> 00:00:03.000\nAlice: Hello everyone.\n\n2\n00:00:04.000 --> 00:00:06.000\nBob: Hi Alice.\n";
+        assert!(looks_like_zoom_vtt(zoom));
+
+        let standard = "WEBVTT\n\n1\n00:00:01.000 --> 00:00:03.000\n<v Alice>Hello everyone.</v>\n";
+        assert!(!looks_like_zoom_vtt(standard));
+    }
+
+    #[test]
+    fn test_parse_zoom_transcript() {
+        let content = "WEBVTT\n\n1\n00:00:01.000 --> 00:00:03.000\nAlice: Hello everyone.\n\n2\n00:00:04.000 --> 00:00:06.000\nBob: Hi Alice.\n";
+        let parsed = ZoomFormat.parse(content);
+
+        assert_eq!(parsed.turns.len(), 2);
+        assert_eq!(parsed.turns[0].speaker, Some("Alice".to_string()));
+        assert_eq!(parsed.turns[0].text, "Hello everyone.");
+        assert_eq!(parsed.turns[0].timestamp, Some(Duration::from_secs(1)));
+    }
+}
@@ -0,0 +1,203 @@
+//! Syncs transcript action items to GitHub issues (chunk199-1).
+//!
+//! Runs after action extraction: each `- ` line in the `ACTIONS:` block gets
+//! filed as a GitHub issue (or updated, if the line already carries a
+//! recorded [`IssueId`]), labeled by its `P0`-`P3` priority. The resulting
+//! `(tracked: owner/repo#123)` reference is written back into the line so a
+//! re-run recognizes it as already tracked instead of filing a duplicate —
+//! the transcript markdown stays the source of truth, GitHub is a mirror.
+//!
+//! Best-effort like `otel`: sync needs a running Tokio reactor to drive the
+//! GitHub HTTP calls, so a synchronous caller (outside any runtime) just gets
+//! the original action text back, untouched.
+
+use std::str::FromStr;
+
+use crate::github::client::GitHubClient;
+use crate::github::{priority_label, GitHubConfig, IssueId};
+
+use super::super::metadata::parse_action_metadata;
+
+const TRACKED_PREFIX: &str = "(tracked: ";
+const TRACKED_SUFFIX: &str = ")";
+
+/// Split a trailing `(tracked: owner/repo#123)` marker off an action line,
+/// returning the remaining text and the recorded issue, if any.
+pub fn split_tracked_ref(raw: &str) -> (&str, Option<IssueId>) {
+    if !raw.ends_with(TRACKED_SUFFIX) {
+        return (raw, None);
+    }
+    match raw.rfind(TRACKED_PREFIX) {
+        Some(start) => {
+            let inner = &raw[start + TRACKED_PREFIX.len()..raw.len() - TRACKED_SUFFIX.len()];
+            match IssueId::from_str(inner) {
+                Ok(issue) => (raw[..start].trim_end(), Some(issue)),
+                Err(_) => (raw, None),
+            }
+        }
+        None => (raw, None),
+    }
+}
+
+/// Push each action line in `actions_text` to GitHub, creating or updating an
+/// issue per line, and return the action text with `(tracked: ...)`
+/// references filled in. Returns `actions_text` unchanged when GitHub sync
+/// isn't configured, or when called outside a Tokio runtime.
+pub fn sync_actions_to_github(config: Option<&GitHubConfig>, actions_text: &str) -> String {
+    let config = match config {
+        Some(c) => c,
+        None => return actions_text.to_string(),
+    };
+
+    let handle = match tokio::runtime::Handle::try_current() {
+        Ok(h) => h,
+        Err(_) => {
+            log::warn!("GitHub issue sync configured but no Tokio runtime is running; skipping");
+            return actions_text.to_string();
+        }
+    };
+
+    let client = GitHubClient::new(&config.token);
+    let mut synced_lines = Vec::with_capacity(actions_text.lines().count());
+
+    for line in actions_text.lines() {
+        let trimmed = line.trim();
+        let (prefix, raw) = if let Some(rest) = trimmed.strip_prefix("- [ ] ") {
+            ("- [ ] ", rest.trim())
+        } else if let Some(rest) = trimmed.strip_prefix("- ") {
+            ("- ", rest.trim())
+        } else {
+            synced_lines.push(line.to_string());
+            continue;
+        };
+
+        if raw.is_empty() {
+            synced_lines.push(line.to_string());
+            continue;
+        }
+
+        let (action_text, existing_issue) = split_tracked_ref(raw);
+        let meta = parse_action_metadata(action_text);
+        let labels = vec![priority_label(meta.priority.as_deref().unwrap_or("P2")).to_string()];
+
+        let synced = handle.block_on(async {
+            match &existing_issue {
+                Some(issue) => client
+                    .update_issue(issue, &meta.clean_title, action_text, &labels)
+                    .await
+                    .map(|_| issue.clone()),
+                None => {
+                    client
+                        .create_issue(&config.repository, &meta.clean_title, action_text, &labels)
+                        .await
+                }
+            }
+        });
+
+        match synced {
+            Ok(issue) => synced_lines.push(format!(
+                "{}{} {}{}{}",
+                prefix, action_text, TRACKED_PREFIX, issue, TRACKED_SUFFIX
+            )),
+            Err(e) => {
+                log::warn!(
+                    "GitHub issue sync failed for '{}': {}",
+                    meta.clean_title,
+                    e
+                );
+                synced_lines.push(line.to_string());
+            }
+        }
+    }
+
+    synced_lines.join("\n")
+}
+
+/// Marker heading for the actions section appended to the transcript file.
+const ACTIONS_SECTION_HEADER: &str = "## Actions";
+
+/// Write (or replace) the `## Actions` section of the transcript file at
+/// `destination` with `synced_actions_text`, so re-runs refresh tracked issue
+/// references instead of appending a duplicate section.
+pub fn write_actions_section(destination: &std::path::Path, synced_actions_text: &str) {
+    if synced_actions_text.trim().is_empty() {
+        return;
+    }
+
+    let content = std::fs::read_to_string(destination).unwrap_or_default();
+    let without_actions = match content.find(ACTIONS_SECTION_HEADER) {
+        Some(idx) => content[..idx].trim_end().to_string(),
+        None => content.trim_end().to_string(),
+    };
+
+    let updated = format!(
+        "{}\n\n{}\n{}\n",
+        without_actions, ACTIONS_SECTION_HEADER, synced_actions_text
+    );
+
+    if let Err(e) = std::fs::write(destination, updated) {
+        log::warn!(
+            "Failed to write actions section to '{}': {}",
+            destination.display(),
+            e
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_tracked_ref_extracts_issue_and_text() {
+        let (text, issue) = split_tracked_ref("P2 Follow up on renewal (tracked: acme/crm#42)");
+        assert_eq!(text, "P2 Follow up on renewal");
+        assert_eq!(
+            issue,
+            Some(IssueId {
+                repository: "acme/crm".to_string(),
+                number: 42,
+            })
+        );
+    }
+
+    #[test]
+    fn test_split_tracked_ref_passes_through_untracked_lines() {
+        let (text, issue) = split_tracked_ref("P2 Follow up on renewal");
+        assert_eq!(text, "P2 Follow up on renewal");
+        assert_eq!(issue, None);
+    }
+
+    #[test]
+    fn test_sync_actions_to_github_is_a_noop_without_config() {
+        let actions_text = "- P2 Follow up on renewal";
+        assert_eq!(
+            sync_actions_to_github(None, actions_text),
+            actions_text.to_string()
+        );
+    }
+
+    #[test]
+    fn test_write_actions_section_replaces_prior_section() {
+        let dir = std::env::temp_dir().join(format!(
+            "dailyos-issues-test-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("transcript.md");
+
+        std::fs::write(&path, "---\ntitle: test\n---\nBody text\n").unwrap();
+        write_actions_section(&path, "- P2 Follow up (tracked: acme/crm#1)");
+        let first = std::fs::read_to_string(&path).unwrap();
+        assert!(first.contains("## Actions"));
+        assert!(first.contains("acme/crm#1"));
+
+        write_actions_section(&path, "- P2 Follow up (tracked: acme/crm#2)");
+        let second = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(second.matches("## Actions").count(), 1);
+        assert!(second.contains("acme/crm#2"));
+        assert!(!second.contains("acme/crm#1"));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
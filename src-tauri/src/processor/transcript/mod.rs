@@ -4,6 +4,7 @@
 //! (summary, wins, risks, decisions, actions) and routing the file to its
 //! proper workspace location.
 
+use std::collections::HashSet;
 use std::path::Path;
 
 use chrono::Utc;
@@ -14,13 +15,20 @@ use crate::types::AiModelConfig;
 use crate::types::{CalendarEvent, CapturedAction, TranscriptResult};
 use crate::util::wrap_user_data;
 
-use super::enrich::parse_enrichment_response;
+use super::enrich::{parse_enrichment_response, ParsedEnrichment};
 use super::hooks;
 
+pub mod chunking;
+pub mod format;
+pub mod issues;
+pub mod otel;
+pub mod speakers;
+
 /// Timeout for transcript AI processing (3 minutes — larger transcripts need more time)
 const TRANSCRIPT_AI_TIMEOUT_SECS: u64 = 180;
 
-/// Maximum transcript content sent to AI (covers ~75 min calls).
+/// Transcript content budget per AI call (covers ~75 min calls in one pass;
+/// longer calls are split across multiple calls by [`chunking::chunk_transcript`]).
 const TRANSCRIPT_MAX_CHARS: usize = 60_000;
 
 /// Head portion kept for tail-biased truncation (attendee context, meeting opening).
@@ -30,8 +38,10 @@ const TRANSCRIPT_HEAD_KEEP: usize = 3_000;
 ///
 /// 1. Read the source file
 /// 2. Route to account dir or archive with YAML frontmatter
-/// 3. Send to Claude for extraction with meeting context
-/// 4. Store outcomes (wins/risks/decisions as captures, actions to SQLite)
+/// 3. Send to Claude for extraction with meeting context — single pass, or
+///    hierarchical map-reduce for transcripts too long for one context window
+/// 4. Store outcomes (wins/risks/decisions as captures, actions synced to
+///    GitHub issues and SQLite)
 /// 5. Run post-enrichment hooks
 pub fn process_transcript(
     workspace: &Path,
@@ -42,6 +52,12 @@ pub fn process_transcript(
     ai_config: Option<&AiModelConfig>,
 ) -> TranscriptResult {
     let source = Path::new(file_path);
+    let github_config = crate::github::GitHubConfig::from_env();
+    let github_config = github_config.as_ref();
+
+    otel::init();
+    let _transcript_span = otel::transcript_span(&meeting.id);
+    let route_span = otel::phase_span("route");
 
     // 1. Read the source file
     let content = match std::fs::read_to_string(source) {
@@ -99,14 +115,20 @@ pub fn process_transcript(
         meeting.title,
         destination.display()
     );
-
-    // 3. Build prompt and invoke Claude
-    let prompt = build_transcript_prompt(meeting, &content);
-    let default_config = AiModelConfig::default();
-    let pty = PtyManager::for_tier(ModelTier::Extraction, ai_config.unwrap_or(&default_config))
-        .with_timeout(TRANSCRIPT_AI_TIMEOUT_SECS);
-    let output = match pty.spawn_claude(workspace, &prompt) {
-        Ok(o) => o.stdout,
+    drop(route_span);
+
+    // 3. Normalize the source format (WebVTT/SRT/Zoom/Otter/plain) to clean
+    // speaker-labeled prose, so format noise (cue numbers, `-->` timestamp
+    // lines) doesn't eat into the prompt window.
+    let source_filename = source.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    let detected_format = format::sniff_format(source_filename, &content);
+    let normalized = detected_format.parser().parse(&content);
+    let transcript_chars = normalized.to_prose().len();
+
+    // Extract via a single pass, or hierarchical map-reduce when the
+    // transcript doesn't fit in one context window.
+    let extraction = match extract_transcript(workspace, meeting, &normalized, ai_config) {
+        Ok(result) => result,
         Err(e) => {
             log::error!(
                 "AI transcript processing failed for '{}': {}",
@@ -123,9 +145,9 @@ pub fn process_transcript(
             };
         }
     };
-
-    // Audit trail (I297)
-    let _ = crate::audit::write_audit_entry(workspace, "transcript", &meeting.id, &output);
+    let parsed = extraction.parsed;
+    let output = extraction.raw_output;
+    otel::record_transcript_length(transcript_chars, extraction.truncated_chars);
 
     // Debug: log raw Claude output for transcript processing
     log::info!(
@@ -139,8 +161,7 @@ pub fn process_transcript(
         }
     );
 
-    // 4. Parse response
-    let parsed = parse_enrichment_response(&output);
+    // 4. Use parsed response
     let summary = parsed.summary.clone();
     let wins = parsed.wins.clone();
     let risks = parsed.risks.clone();
@@ -151,17 +172,25 @@ pub fn process_transcript(
     // Extract actions to SQLite
     let mut extracted_actions = Vec::new();
     if let Some(ref actions_text) = parsed.actions_text {
+        // Push each action to GitHub (create-or-update) before anything else
+        // sees the text, so the tracked issue reference flows into both the
+        // DB and the transcript file (chunk199-1).
+        let synced_actions_text =
+            issues::sync_actions_to_github(github_config, actions_text);
+
         if let Some(db) = db {
             extract_transcript_actions(
-                actions_text,
+                &synced_actions_text,
                 &meeting.id,
                 &meeting.title,
                 db,
                 meeting.account.as_deref(),
             );
         }
-        // Parse for return value
-        for line in actions_text.lines() {
+        // Parse for return value — dedupe by normalized title since
+        // map-reduce chunk overlap can re-surface the same action (chunk198-3).
+        let mut seen_titles = HashSet::new();
+        for line in synced_actions_text.lines() {
             let trimmed = line.trim();
             let raw = if let Some(rest) = trimmed.strip_prefix("- ") {
                 rest.trim()
@@ -169,7 +198,11 @@ pub fn process_transcript(
                 continue;
             };
             if !raw.is_empty() {
-                let meta = super::metadata::parse_action_metadata(raw);
+                let (action_text, _) = issues::split_tracked_ref(raw);
+                let meta = super::metadata::parse_action_metadata(action_text);
+                if !seen_titles.insert(meta.clean_title.trim().to_lowercase()) {
+                    continue;
+                }
                 extracted_actions.push(CapturedAction {
                     title: meta.clean_title,
                     owner: meta.account,
@@ -177,8 +210,14 @@ pub fn process_transcript(
                 });
             }
         }
+
+        issues::write_actions_section(&destination, &synced_actions_text);
     }
 
+    otel::record_outcomes(extracted_actions.len(), wins.len(), risks.len(), decisions.len());
+
+    let db_write_span = otel::phase_span("db_write");
+
     // Store captures (wins, risks, decisions)
     if let Some(db) = db {
         for win in &wins {
@@ -246,6 +285,35 @@ pub fn process_transcript(
         }
     }
 
+    // 4c. Speaker diarization & talk-time analytics — surface a `talk_ratio`
+    // signal when a rep dominates the conversation (I44 follow-up).
+    let talk_time = speakers::analyze_talk_time(&normalized, &meeting.attendees);
+    if let (Some(db), Some(analysis)) = (db, &talk_time) {
+        let entity_type = meeting
+            .linked_entities
+            .as_ref()
+            .and_then(|e| e.first())
+            .map(|e| e.entity_type.as_str())
+            .unwrap_or("account");
+        let entity_id = meeting
+            .linked_entities
+            .as_ref()
+            .and_then(|e| e.first())
+            .map(|e| e.id.as_str())
+            .or(meeting.account.as_deref())
+            .unwrap_or(&meeting.id);
+
+        let _ = crate::signals::bus::emit_signal(
+            db,
+            entity_type,
+            entity_id,
+            "talk_ratio",
+            "transcript",
+            Some(&format!("{:.3}", analysis.internal_ratio)),
+            0.75,
+        );
+    }
+
     // 5. Run post-enrichment hooks
     if let Some(db) = db {
         let ctx = hooks::EnrichmentContext {
@@ -289,6 +357,7 @@ pub fn process_transcript(
             log::warn!("Failed to log transcript processing: {}", e);
         }
     }
+    drop(db_write_span);
 
     // 7. Append wins to impact log
     if !wins.is_empty() {
@@ -307,6 +376,11 @@ pub fn process_transcript(
         None
     };
 
+    let (speaker_stats, talk_ratio) = match talk_time {
+        Some(analysis) => (analysis.speakers, Some(analysis.internal_ratio)),
+        None => (Vec::new(), None),
+    };
+
     TranscriptResult {
         status: "success".to_string(),
         summary: Some(summary),
@@ -317,10 +391,186 @@ pub fn process_transcript(
         actions: extracted_actions,
         discussion,
         analysis,
+        speaker_stats,
+        talk_ratio,
         message: debug_message,
     }
 }
 
+/// Result of running AI extraction over a transcript (chunk198-4): the
+/// parsed response, the final AI call's raw output (for the empty-summary
+/// debug message), and how many characters the per-call truncation safety
+/// net dropped — normally 0, since map-reduce means nothing should hit it.
+struct ExtractionOutcome {
+    parsed: ParsedEnrichment,
+    raw_output: String,
+    truncated_chars: usize,
+}
+
+/// How many characters over `TRANSCRIPT_MAX_CHARS` a prompt's source content
+/// is, i.e. how much `truncate_transcript` will drop from it.
+fn truncation_amount(content: &str) -> usize {
+    content.len().saturating_sub(TRANSCRIPT_MAX_CHARS)
+}
+
+/// Run AI extraction over a normalized transcript, using hierarchical
+/// map-reduce when it doesn't fit in one context window instead of throwing
+/// away the middle of the call the way `truncate_transcript` used to
+/// (chunk198-3).
+///
+/// Falls back to the existing single-pass behavior when the transcript fits
+/// within `TRANSCRIPT_MAX_CHARS`. Otherwise: map — run the extraction prompt
+/// on each chunk independently — then reduce — feed the partial results back
+/// to the model to dedupe and merge into one final extraction.
+fn extract_transcript(
+    workspace: &Path,
+    meeting: &CalendarEvent,
+    normalized: &format::NormalizedTranscript,
+    ai_config: Option<&AiModelConfig>,
+) -> Result<ExtractionOutcome, String> {
+    let default_config = AiModelConfig::default();
+    let pty = PtyManager::for_tier(ModelTier::Extraction, ai_config.unwrap_or(&default_config))
+        .with_timeout(TRANSCRIPT_AI_TIMEOUT_SECS);
+
+    let run = |prompt: &str| -> Result<String, String> {
+        let extract_span = otel::phase_span("extract");
+        let started = std::time::Instant::now();
+        let result = pty.spawn_claude(workspace, prompt);
+        let elapsed = started.elapsed();
+        otel::record_extraction_duration(elapsed, elapsed.as_secs() >= TRANSCRIPT_AI_TIMEOUT_SECS);
+        drop(extract_span);
+
+        let output = result.map_err(|e| e.to_string())?.stdout;
+        let _ = crate::audit::write_audit_entry(workspace, "transcript", &meeting.id, &output);
+        Ok(output)
+    };
+    let parse = |output: &str| -> ParsedEnrichment {
+        let _parse_span = otel::phase_span("parse");
+        parse_enrichment_response(output)
+    };
+
+    let prose = normalized.to_prose();
+    if prose.len() <= TRANSCRIPT_MAX_CHARS {
+        let output = run(&build_transcript_prompt(meeting, &prose))?;
+        let parsed = parse(&output);
+        return Ok(ExtractionOutcome {
+            parsed,
+            raw_output: output,
+            truncated_chars: truncation_amount(&prose),
+        });
+    }
+
+    // Map: extract each chunk independently.
+    let chunks = chunking::chunk_transcript(normalized, TRANSCRIPT_MAX_CHARS);
+    log::info!(
+        "Transcript for '{}' is {} chars, splitting into {} chunks for map-reduce extraction",
+        meeting.title,
+        prose.len(),
+        chunks.len()
+    );
+
+    let mut partials = Vec::with_capacity(chunks.len());
+    let mut truncated_chars = 0;
+    for chunk in &chunks {
+        let chunk_prose = chunk.to_prose();
+        truncated_chars += truncation_amount(&chunk_prose);
+        let output = run(&build_transcript_prompt(meeting, &chunk_prose))?;
+        partials.push(parse(&output));
+    }
+
+    // Reduce: consolidate the partial extractions into one final result.
+    let output = run(&build_reduce_prompt(meeting, &partials))?;
+    let parsed = parse(&output);
+    Ok(ExtractionOutcome {
+        parsed,
+        raw_output: output,
+        truncated_chars,
+    })
+}
+
+/// Build the reduce-step prompt for map-reduce summarization: feeds each
+/// chunk's partial extraction back to the model to dedupe (chunk overlap
+/// re-surfaces the same wins/risks/actions) and merge into one final result,
+/// in the same response format `parse_enrichment_response` expects.
+fn build_reduce_prompt(meeting: &CalendarEvent, partials: &[ParsedEnrichment]) -> String {
+    let title = if meeting.title.trim().is_empty() {
+        "Untitled meeting"
+    } else {
+        &meeting.title
+    };
+
+    let mut partial_extractions = String::new();
+    for (i, partial) in partials.iter().enumerate() {
+        partial_extractions.push_str(&format!("--- Chunk {} ---\n", i + 1));
+        partial_extractions.push_str(&format!("Summary: {}\n", partial.summary));
+        for topic in &partial.discussion {
+            partial_extractions.push_str(&format!("Discussion: {}\n", topic));
+        }
+        if let Some(analysis) = &partial.analysis {
+            partial_extractions.push_str(&format!("Analysis: {}\n", analysis));
+        }
+        if let Some(actions) = &partial.actions_text {
+            partial_extractions.push_str("Actions:\n");
+            partial_extractions.push_str(actions);
+            partial_extractions.push('\n');
+        }
+        for win in &partial.wins {
+            partial_extractions.push_str(&format!("Win: {}\n", win));
+        }
+        for risk in &partial.risks {
+            partial_extractions.push_str(&format!("Risk: {}\n", risk));
+        }
+        for decision in &partial.decisions {
+            partial_extractions.push_str(&format!("Decision: {}\n", decision));
+        }
+        partial_extractions.push('\n');
+    }
+
+    format!(
+        r#"You previously extracted partial summaries from {chunk_count} sequential chunks of a
+long meeting transcript for "{title}" (the call was too long to analyze in one
+pass, so it was split on speaker-turn boundaries with a small overlap between
+chunks).
+
+Consolidate these partial extractions into ONE final result for the whole call:
+- Merge and dedupe — adjacent chunks overlap by a turn or two, so the same
+  action, win, risk, or decision may appear more than once. Keep one copy.
+- Preserve the chronological order discussion topics occurred in the call.
+- Write one overall SUMMARY and ANALYSIS for the whole call, not per-chunk.
+
+Respond in exactly this format:
+
+SUMMARY: <2-3 sentence executive summary of the whole call>
+
+DISCUSSION:
+- <Topic 1>: <What was discussed, decided, or committed to>
+- <Topic 2>: ...
+END_DISCUSSION
+
+ANALYSIS: <1-2 sentences of strategic TAM-perspective insight for the whole call>
+
+ACTIONS:
+- <concise action title> P1/P2/P3 @Account due: YYYY-MM-DD #"context sentence"
+END_ACTIONS
+WINS:
+- <customer win, positive outcome, expansion signal>
+END_WINS
+RISKS:
+- <churn signal, concern, blocker>
+END_RISKS
+DECISIONS:
+- <explicit decision made, who decided, any conditions>
+END_DECISIONS
+
+Partial extractions:
+{partial_extractions}
+"#,
+        chunk_count = partials.len(),
+        title = wrap_user_data(title),
+        partial_extractions = wrap_user_data(&partial_extractions),
+    )
+}
+
 /// Extract actions from AI output, using meeting ID as source_id for meeting-scoped queries.
 fn extract_transcript_actions(
     actions_text: &str,
@@ -331,6 +581,9 @@ fn extract_transcript_actions(
 ) {
     let now = Utc::now().to_rfc3339();
     let mut count = 0;
+    // Map-reduce chunk overlap can re-surface the same action more than once
+    // (chunk198-3) — dedupe by normalized title before upserting.
+    let mut seen_titles = HashSet::new();
 
     for line in actions_text.lines() {
         let trimmed = line.trim();
@@ -346,18 +599,23 @@ fn extract_transcript_actions(
             continue;
         }
 
-        let meta = super::metadata::parse_action_metadata(raw_title);
+        let (raw_title, _) = issues::split_tracked_ref(raw_title);
+        let item = super::metadata::parse_action_item(raw_title);
+
+        if !seen_titles.insert(item.text.trim().to_lowercase()) {
+            continue;
+        }
 
-        let status = if meta.is_waiting {
+        let status = if item.is_waiting {
             "waiting".to_string()
         } else {
             "proposed".to_string()
         };
 
-        // Resolve @Tag to a real account ID; fall back to meeting-level account.
-        // If the tag doesn't match any account, use None to avoid FK violations.
-        let account_id = meta
-            .account
+        // Resolve @Tag/(owner: ...) to a real account ID; fall back to meeting-level
+        // account. If the tag doesn't match any account, use None to avoid FK violations.
+        let account_id = item
+            .owner
             .as_deref()
             .and_then(|tag| {
                 db.get_account_by_name(tag)
@@ -369,19 +627,23 @@ fn extract_transcript_actions(
 
         let action = crate::db::DbAction {
             id: format!("transcript-{}-{}", meeting_id, count),
-            title: meta.clean_title,
-            priority: meta.priority.unwrap_or_else(|| "P2".to_string()),
+            title: item.text,
+            priority: item
+                .priority
+                .as_str()
+                .unwrap_or("P2")
+                .to_string(),
             status,
             created_at: now.clone(),
-            due_date: meta.due_date,
+            due_date: item.due.map(|d| d.to_string()),
             completed_at: None,
             account_id,
             project_id: None,
             source_type: Some("transcript".to_string()),
             source_id: Some(meeting_id.to_string()),
             source_label: Some(meeting_title.to_string()),
-            context: meta.context,
-            waiting_on: if meta.is_waiting {
+            context: item.context,
+            waiting_on: if item.is_waiting {
                 Some("true".to_string())
             } else {
                 None
@@ -538,13 +800,15 @@ fn build_frontmatter(meeting: &CalendarEvent, date: &str) -> String {
     let now = Utc::now().to_rfc3339();
 
     format!(
-        "---\nmeeting_id: \"{}\"\nmeeting_title: \"{}\"\n{}meeting_type: \"{}\"\nmeeting_date: \"{}\"\nprocessed_at: \"{}\"\nsource: transcript\n---\n",
+        "---\nmeeting_id: \"{}\"\nmeeting_title: \"{}\"\n{}meeting_type: \"{}\"\nmeeting_date: \"{}\"\nprocessed_at: \"{}\"\nsource: transcript\nbuild_version: \"{}\"\nenrichment_prompt_version: \"{}\"\n---\n",
         meeting.id,
         meeting.title.replace('"', "\\\""),
         account_line,
         meeting_type,
         date,
         now,
+        crate::version::build_version(),
+        crate::version::ENRICHMENT_PROMPT_VERSION,
     )
 }
 
@@ -608,6 +872,8 @@ impl Default for TranscriptResult {
             actions: Vec::new(),
             discussion: Vec::new(),
             analysis: None,
+            speaker_stats: Vec::new(),
+            talk_ratio: None,
             message: None,
         }
     }
@@ -667,6 +933,50 @@ mod tests {
         assert!(prompt.contains("Some transcript"));
     }
 
+    #[test]
+    fn test_build_reduce_prompt_merges_partials() {
+        let meeting = test_meeting();
+        let partials = vec![
+            ParsedEnrichment {
+                file_type: "transcript".to_string(),
+                account: None,
+                meeting_name: None,
+                summary: "First half covered pricing.".to_string(),
+                discussion: vec!["Pricing: discussed renewal tiers".to_string()],
+                analysis: Some("Account is trending toward expansion.".to_string()),
+                actions_text: Some("- Send pricing sheet P2 @Acme".to_string()),
+                actions: vec![],
+                wins: vec!["Customer praised onboarding".to_string()],
+                risks: vec![],
+                decisions: vec![],
+            },
+            ParsedEnrichment {
+                file_type: "transcript".to_string(),
+                account: None,
+                meeting_name: None,
+                summary: "Second half covered rollout timeline.".to_string(),
+                discussion: vec!["Rollout: agreed on phased launch".to_string()],
+                analysis: None,
+                actions_text: None,
+                actions: vec![],
+                wins: vec![],
+                risks: vec!["Champion mentioned budget freeze".to_string()],
+                decisions: vec!["Phased rollout starting Q2".to_string()],
+            },
+        ];
+
+        let prompt = build_reduce_prompt(&meeting, &partials);
+
+        assert!(prompt.contains("2 sequential chunks"));
+        assert!(prompt.contains("Acme QBR"));
+        assert!(prompt.contains("Merge and dedupe"));
+        assert!(prompt.contains("First half covered pricing."));
+        assert!(prompt.contains("Second half covered rollout timeline."));
+        assert!(prompt.contains("Send pricing sheet P2 @Acme"));
+        assert!(prompt.contains("Champion mentioned budget freeze"));
+        assert!(prompt.contains("Phased rollout starting Q2"));
+    }
+
     #[test]
     fn test_truncate_transcript_short() {
         let short = "Short transcript content";
@@ -697,6 +1007,11 @@ mod tests {
         assert!(fm.contains("meeting_title: \"Acme QBR\""));
         assert!(fm.contains("account: \"Acme Corp\""));
         assert!(fm.contains("source: transcript"));
+        assert!(fm.contains("build_version: \""));
+        assert!(fm.contains(&format!(
+            "enrichment_prompt_version: \"{}\"",
+            crate::version::ENRICHMENT_PROMPT_VERSION
+        )));
     }
 
     #[test]
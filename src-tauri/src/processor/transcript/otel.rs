@@ -0,0 +1,166 @@
+//! Optional OpenTelemetry instrumentation for the transcript pipeline
+//! (chunk198-4 follow-up to I44).
+//!
+//! Every hook here compiles to nothing unless the `otel` cargo feature is
+//! on, so non-observability builds pay zero cost. When enabled, [`init`]
+//! wires traces, metrics, and logs through a single OTLP endpoint — the
+//! `OTEL_EXPORTER_OTLP_ENDPOINT` env var the opentelemetry SDK already
+//! reads, so there's no separate endpoint setting to plumb through the app
+//! config — and [`phase_span`]/[`transcript_span`] give `process_transcript`
+//! a parent span per invocation with child spans per phase.
+//!
+//! `Cargo.toml` needs, behind an `otel` feature flag:
+//! `opentelemetry`, `opentelemetry_otlp`, `opentelemetry_sdk`, `tracing`,
+//! `tracing-subscriber` (with the `registry` feature), and
+//! `tracing-opentelemetry` — pinned to mutually-compatible versions, since
+//! `opentelemetry`/`tracing-opentelemetry` move in lockstep upstream. No
+//! other module in this crate uses the `tracing` family (everything else
+//! logs through the `log` facade), so this feature is the sole consumer.
+
+#[cfg(feature = "otel")]
+mod enabled {
+    use std::sync::OnceLock;
+    use std::time::Duration;
+
+    use opentelemetry::metrics::{Counter, Histogram};
+    use opentelemetry::trace::TracerProvider as _;
+    use opentelemetry::{global, KeyValue};
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::util::SubscriberInitExt;
+
+    pub type SpanGuard = tracing::span::EnteredSpan;
+
+    static INIT: OnceLock<()> = OnceLock::new();
+    static ACTIONS: OnceLock<Counter<u64>> = OnceLock::new();
+    static WINS: OnceLock<Counter<u64>> = OnceLock::new();
+    static RISKS: OnceLock<Counter<u64>> = OnceLock::new();
+    static DECISIONS: OnceLock<Counter<u64>> = OnceLock::new();
+    static TRANSCRIPT_LENGTH: OnceLock<Histogram<u64>> = OnceLock::new();
+    static TRUNCATED_CHARS: OnceLock<Histogram<u64>> = OnceLock::new();
+    static EXTRACTION_DURATION: OnceLock<Histogram<u64>> = OnceLock::new();
+
+    /// Install the OTLP trace + metric pipelines, once per process. Safe to
+    /// call on every `process_transcript` invocation. The OTLP exporters need
+    /// a running Tokio reactor to hand their batches off to; when
+    /// `process_transcript` runs outside of one (e.g. a synchronous CLI
+    /// invocation), we skip the pipeline rather than panic — observability
+    /// is best-effort, never a reason to fail a transcript.
+    pub fn init() {
+        INIT.get_or_init(|| {
+            if tokio::runtime::Handle::try_current().is_err() {
+                log::warn!("otel feature enabled but no Tokio runtime is running; skipping OTLP pipeline init");
+                return;
+            }
+
+            if let Ok(provider) = opentelemetry_otlp::new_pipeline()
+                .tracing()
+                .with_exporter(opentelemetry_otlp::new_exporter().tonic())
+                .install_batch(opentelemetry_sdk::runtime::Tokio)
+            {
+                let tracer = provider.tracer("daily-operating-system.transcript");
+                let otel_layer = tracing_opentelemetry::layer().with_tracer(tracer);
+                let _ = tracing_subscriber::registry().with(otel_layer).try_init();
+            }
+
+            if let Ok(provider) = opentelemetry_otlp::new_pipeline()
+                .metrics(opentelemetry_sdk::runtime::Tokio)
+                .with_exporter(opentelemetry_otlp::new_exporter().tonic())
+                .build()
+            {
+                global::set_meter_provider(provider);
+            }
+        });
+    }
+
+    fn meter() -> opentelemetry::metrics::Meter {
+        global::meter("daily-operating-system.transcript")
+    }
+
+    /// Parent span covering one `process_transcript` invocation.
+    pub fn transcript_span(meeting_id: &str) -> SpanGuard {
+        tracing::info_span!("transcript.process", meeting_id = %meeting_id).entered()
+    }
+
+    /// Child span for one pipeline phase (route / extract / parse / db_write).
+    pub fn phase_span(phase: &'static str) -> SpanGuard {
+        tracing::info_span!("transcript.phase", phase).entered()
+    }
+
+    pub fn record_outcomes(actions: usize, wins: usize, risks: usize, decisions: usize) {
+        ACTIONS
+            .get_or_init(|| meter().u64_counter("transcript.actions_extracted").init())
+            .add(actions as u64, &[]);
+        WINS.get_or_init(|| meter().u64_counter("transcript.wins_extracted").init())
+            .add(wins as u64, &[]);
+        RISKS
+            .get_or_init(|| meter().u64_counter("transcript.risks_extracted").init())
+            .add(risks as u64, &[]);
+        DECISIONS
+            .get_or_init(|| meter().u64_counter("transcript.decisions_extracted").init())
+            .add(decisions as u64, &[]);
+    }
+
+    pub fn record_transcript_length(chars: usize, truncated_chars: usize) {
+        TRANSCRIPT_LENGTH
+            .get_or_init(|| meter().u64_histogram("transcript.length_chars").init())
+            .record(chars as u64, &[]);
+        TRUNCATED_CHARS
+            .get_or_init(|| meter().u64_histogram("transcript.truncated_chars").init())
+            .record(truncated_chars as u64, &[]);
+    }
+
+    pub fn record_extraction_duration(elapsed: Duration, timed_out: bool) {
+        EXTRACTION_DURATION
+            .get_or_init(|| meter().u64_histogram("transcript.extraction_duration_ms").init())
+            .record(elapsed.as_millis() as u64, &[KeyValue::new("timed_out", timed_out)]);
+    }
+}
+
+#[cfg(not(feature = "otel"))]
+mod disabled {
+    use std::time::Duration;
+
+    /// No-op stand-in for the real span guard. A real (if empty) `Drop` impl
+    /// so call sites can `drop()` it explicitly to close a phase span early,
+    /// same as the real `EnteredSpan` guard.
+    pub struct SpanGuard;
+
+    impl Drop for SpanGuard {
+        fn drop(&mut self) {}
+    }
+
+    pub fn init() {}
+    pub fn transcript_span(_meeting_id: &str) -> SpanGuard {
+        SpanGuard
+    }
+    pub fn phase_span(_phase: &'static str) -> SpanGuard {
+        SpanGuard
+    }
+    pub fn record_outcomes(_actions: usize, _wins: usize, _risks: usize, _decisions: usize) {}
+    pub fn record_transcript_length(_chars: usize, _truncated_chars: usize) {}
+    pub fn record_extraction_duration(_elapsed: Duration, _timed_out: bool) {}
+}
+
+#[cfg(feature = "otel")]
+pub use enabled::*;
+
+#[cfg(not(feature = "otel"))]
+pub use disabled::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_record_extraction_duration_is_a_zero_cost_noop_without_the_feature() {
+        // Exercised for its side effects (none when the `otel` feature is
+        // off) — this just guards against the disabled stubs failing to compile.
+        init();
+        let _guard = transcript_span("test-meeting");
+        let _phase_guard = phase_span("route");
+        record_outcomes(1, 1, 0, 0);
+        record_transcript_length(1_000, 0);
+        record_extraction_duration(Duration::from_millis(500), false);
+    }
+}
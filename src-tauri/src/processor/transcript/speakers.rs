@@ -0,0 +1,180 @@
+//! Speaker diarization and talk-time analytics.
+//!
+//! Once `format::NormalizedTranscript` turns carry speaker labels, this pass
+//! tallies per-speaker word counts and estimated talk time, splits speakers
+//! into internal (matched against `meeting.attendees`) vs. customer-side, and
+//! derives a customer-vs-internal talk ratio. A rep dominating a customer call
+//! is a coachable risk signal that wins/risks extraction alone won't catch.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::types::SpeakerTalkStats;
+
+use super::format::NormalizedTranscript;
+
+/// Bucket for turns whose speaker couldn't be attributed.
+const UNATTRIBUTED: &str = "unattributed";
+
+/// Talk-time analysis for a transcript: per-speaker stats plus the overall
+/// customer-vs-internal ratio.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TalkTimeAnalysis {
+    pub speakers: Vec<SpeakerTalkStats>,
+    /// Share of total words held by internal attendees, in `[0.0, 1.0]`.
+    pub internal_ratio: f64,
+}
+
+/// True when `speaker` matches one of the meeting's internal attendees, using
+/// the same lenient substring match as account-tag resolution elsewhere in
+/// this pipeline (attendee lists are often "Full Name <email>" or just a
+/// first name, so an exact match is too strict).
+fn is_internal_speaker(speaker: &str, attendees: &[String]) -> bool {
+    let speaker_lower = speaker.to_lowercase();
+    attendees.iter().any(|attendee| {
+        let attendee_lower = attendee.to_lowercase();
+        attendee_lower.contains(&speaker_lower) || speaker_lower.contains(&attendee_lower)
+    })
+}
+
+/// Analyze talk time across a normalized transcript's turns, folding them into
+/// a `speaker -> (word_count, talk_duration)` tally keyed on speaker name
+/// (unlabeled turns fold into a single "unattributed" bucket). Returns `None`
+/// when no turn carried a speaker label at all — there's nothing to diarize
+/// (e.g. a `plain`-format transcript with no dialogue structure).
+pub fn analyze_talk_time(
+    normalized: &NormalizedTranscript,
+    attendees: &[String],
+) -> Option<TalkTimeAnalysis> {
+    if !normalized.turns.iter().any(|turn| turn.speaker.is_some()) {
+        return None;
+    }
+
+    let mut tally: HashMap<String, (usize, Duration)> = HashMap::new();
+    let mut order: Vec<String> = Vec::new();
+
+    for (i, turn) in normalized.turns.iter().enumerate() {
+        let key = turn
+            .speaker
+            .clone()
+            .unwrap_or_else(|| UNATTRIBUTED.to_string());
+        let word_count = turn.text.split_whitespace().count();
+        // Estimate this turn's talk time as the gap to the next timestamped
+        // turn; turns without timestamps (or the last turn) contribute 0.
+        let duration = turn
+            .timestamp
+            .zip(normalized.turns.get(i + 1).and_then(|next| next.timestamp))
+            .map(|(start, next)| next.saturating_sub(start))
+            .unwrap_or_default();
+
+        if !tally.contains_key(&key) {
+            order.push(key.clone());
+        }
+        let entry = tally.entry(key).or_insert((0, Duration::ZERO));
+        entry.0 += word_count;
+        entry.1 += duration;
+    }
+
+    let total_words: usize = tally.values().map(|(words, _)| words).sum();
+    if total_words == 0 {
+        return None;
+    }
+
+    let mut internal_words = 0usize;
+    let mut speakers = Vec::with_capacity(order.len());
+    for speaker in order {
+        let (word_count, talk_duration) = tally[&speaker];
+        let is_internal = speaker != UNATTRIBUTED && is_internal_speaker(&speaker, attendees);
+        if is_internal {
+            internal_words += word_count;
+        }
+        speakers.push(SpeakerTalkStats {
+            speaker,
+            is_internal,
+            word_count,
+            talk_seconds: talk_duration.as_secs_f64(),
+            share: word_count as f64 / total_words as f64,
+        });
+    }
+
+    Some(TalkTimeAnalysis {
+        speakers,
+        internal_ratio: internal_words as f64 / total_words as f64,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::processor::transcript::format::TranscriptTurn;
+
+    fn turn(speaker: Option<&str>, text: &str, secs: Option<u64>) -> TranscriptTurn {
+        TranscriptTurn {
+            speaker: speaker.map(String::from),
+            text: text.to_string(),
+            timestamp: secs.map(Duration::from_secs),
+        }
+    }
+
+    #[test]
+    fn test_analyze_talk_time_splits_internal_and_customer() {
+        let normalized = NormalizedTranscript {
+            turns: vec![
+                turn(Some("Alice"), "Welcome to the call everyone", Some(0)),
+                turn(Some("Bob"), "Thanks for having us", Some(5)),
+                turn(Some("Alice"), "Let's review the renewal", Some(8)),
+            ],
+        };
+        let attendees = vec!["Alice Rep".to_string()];
+
+        let analysis = analyze_talk_time(&normalized, &attendees).expect("should diarize");
+        assert_eq!(analysis.speakers.len(), 2);
+
+        let alice = analysis.speakers.iter().find(|s| s.speaker == "Alice").unwrap();
+        assert!(alice.is_internal);
+        let bob = analysis.speakers.iter().find(|s| s.speaker == "Bob").unwrap();
+        assert!(!bob.is_internal);
+
+        // Alice spoke 9 words across 2 turns, Bob spoke 4 — internal dominates.
+        assert!(analysis.internal_ratio > 0.5);
+    }
+
+    #[test]
+    fn test_analyze_talk_time_unattributed_bucket() {
+        let normalized = NormalizedTranscript {
+            turns: vec![
+                turn(Some("Alice"), "Hello", Some(0)),
+                turn(None, "inaudible crosstalk", Some(2)),
+            ],
+        };
+        let analysis = analyze_talk_time(&normalized, &[]).expect("should diarize");
+
+        let unattributed = analysis
+            .speakers
+            .iter()
+            .find(|s| s.speaker == "unattributed")
+            .unwrap();
+        assert!(!unattributed.is_internal);
+    }
+
+    #[test]
+    fn test_analyze_talk_time_none_without_speaker_labels() {
+        let normalized = NormalizedTranscript {
+            turns: vec![turn(None, "Just a paragraph of notes.", None)],
+        };
+        assert!(analyze_talk_time(&normalized, &[]).is_none());
+    }
+
+    #[test]
+    fn test_analyze_talk_time_estimates_duration_from_timestamp_gaps() {
+        let normalized = NormalizedTranscript {
+            turns: vec![
+                turn(Some("Alice"), "one two three", Some(0)),
+                turn(Some("Bob"), "four five", Some(10)),
+            ],
+        };
+        let analysis = analyze_talk_time(&normalized, &[]).expect("should diarize");
+        let alice = analysis.speakers.iter().find(|s| s.speaker == "Alice").unwrap();
+        assert_eq!(alice.talk_seconds, 10.0);
+    }
+}
@@ -297,14 +297,28 @@ async fn process_sync_row(
     // DB lock released — run the AI pipeline WITHOUT holding the mutex.
     // This was the critical hang: the pipeline (AI calls, file I/O) ran
     // while holding db.lock(), blocking the entire app.
-    let result = sync::process_fetched_transcript_without_db(
-        &row.id,
-        &calendar_event,
-        &transcript,
-        &workspace,
-        &profile,
-        ai_config.as_ref(),
-    );
+    //
+    // The pipeline also does its own `handle.block_on` (GitHub issue sync),
+    // so it has to run off this worker thread — spawn_blocking moves it to
+    // a thread where nesting a blocking runtime call is safe.
+    let sync_id = row.id.clone();
+    let blocking_event = calendar_event.clone();
+    let blocking_transcript = transcript.clone();
+    let result = match tokio::task::spawn_blocking(move || {
+        sync::process_fetched_transcript_without_db(
+            &sync_id,
+            &blocking_event,
+            &blocking_transcript,
+            &workspace,
+            &profile,
+            ai_config.as_ref(),
+        )
+    })
+    .await
+    {
+        Ok(r) => r,
+        Err(e) => Err(format!("Transcript processing task panicked: {}", e)),
+    };
 
     // Re-acquire lock briefly to write results + captures
     {
@@ -0,0 +1,229 @@
+//! Snapshot/export subsystem for the `_today/data` working set (I197)
+//!
+//! Directive, prep, week-overview, and email JSONs are loaded piecemeal via
+//! `load_directive`, `load_prep_json`, `load_week_json`, and
+//! `load_emails_json_with_sync`, with no way to capture a day's full state for
+//! debugging, reproduction, or archival. This module bundles every artifact under
+//! `_today/data` into a single self-contained, versioned directory under
+//! `_today/snapshots/<timestamp>/`, with a manifest recording the schema version,
+//! capture timestamp, source profile, and a per-file checksum. A user can file a bug
+//! with an attached snapshot; a maintainer can `restore_snapshot` it into a scratch
+//! `today_dir` and replay the exact inputs that produced a given delivery.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Schema version of the snapshot manifest format itself (bump on breaking changes).
+const SNAPSHOT_SCHEMA_VERSION: u32 = 1;
+
+/// Manifest describing the contents of a captured snapshot.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotManifest {
+    pub schema_version: u32,
+    /// RFC 3339 capture timestamp, supplied by the caller rather than read from the
+    /// clock so captures stay deterministic and testable.
+    pub captured_at: String,
+    /// `DirectiveContext::profile`, when known at capture time.
+    pub profile: Option<String>,
+    pub files: Vec<SnapshotFileEntry>,
+}
+
+/// One captured file, relative to `_today/data`, with its checksum.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotFileEntry {
+    pub relative_path: String,
+    pub sha256: String,
+    pub size_bytes: u64,
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    hex::encode(hasher.finalize())
+}
+
+/// Recursively collect every regular file under `dir`.
+fn collect_files(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut files = Vec::new();
+    if !dir.exists() {
+        return Ok(files);
+    }
+    let mut stack = vec![dir.to_path_buf()];
+    while let Some(current) = stack.pop() {
+        for entry in std::fs::read_dir(&current)
+            .map_err(|e| format!("Failed to read {}: {}", current.display(), e))?
+        {
+            let entry = entry.map_err(|e| format!("Failed to read dir entry: {}", e))?;
+            let path = entry.path();
+            if path.is_dir() {
+                stack.push(path);
+            } else {
+                files.push(path);
+            }
+        }
+    }
+    Ok(files)
+}
+
+/// Capture every artifact under `<today_dir>/data` into a versioned snapshot
+/// directory at `<today_dir>/snapshots/<captured_at>/`, alongside a manifest. Returns
+/// the path to the snapshot directory.
+pub fn capture_snapshot(
+    today_dir: &Path,
+    captured_at: &str,
+    profile: Option<String>,
+) -> Result<PathBuf, String> {
+    let data_dir = today_dir.join("data");
+    if !data_dir.exists() {
+        return Err(format!("No data directory at {}", data_dir.display()));
+    }
+
+    // RFC 3339 timestamps contain `:` which isn't a safe directory-name character
+    // on all platforms.
+    let snapshot_name = captured_at.replace([':', '.'], "-");
+    let snapshot_dir = today_dir.join("snapshots").join(&snapshot_name);
+    std::fs::create_dir_all(&snapshot_dir)
+        .map_err(|e| format!("Failed to create snapshot dir: {}", e))?;
+
+    let mut entries = Vec::new();
+    for source_file in collect_files(&data_dir)? {
+        // Snapshots are self-contained; don't re-capture a prior snapshot nested
+        // under data/ (shouldn't happen, but guards against accidental recursion).
+        let relative = source_file
+            .strip_prefix(&data_dir)
+            .map_err(|_| "File outside data directory".to_string())?;
+        let dest = snapshot_dir.join(relative);
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        let bytes = std::fs::read(&source_file)
+            .map_err(|e| format!("Failed to read {}: {}", source_file.display(), e))?;
+        std::fs::write(&dest, &bytes)
+            .map_err(|e| format!("Failed to write {}: {}", dest.display(), e))?;
+
+        entries.push(SnapshotFileEntry {
+            relative_path: relative.to_string_lossy().replace('\\', "/"),
+            sha256: sha256_hex(&bytes),
+            size_bytes: bytes.len() as u64,
+        });
+    }
+    entries.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+
+    let manifest = SnapshotManifest {
+        schema_version: SNAPSHOT_SCHEMA_VERSION,
+        captured_at: captured_at.to_string(),
+        profile,
+        files: entries,
+    };
+    let manifest_json = serde_json::to_vec_pretty(&manifest)
+        .map_err(|e| format!("Failed to serialize snapshot manifest: {}", e))?;
+    crate::util::atomic_write(&snapshot_dir.join("snapshot-manifest.json"), &manifest_json)
+        .map_err(|e| format!("Failed to write snapshot manifest: {}", e))?;
+
+    Ok(snapshot_dir)
+}
+
+/// Unpack a snapshot directory (as produced by [`capture_snapshot`]) into
+/// `<target_today_dir>/data`, verifying each file's checksum against the manifest
+/// before restoring it. Returns the number of files restored.
+pub fn restore_snapshot(snapshot_dir: &Path, target_today_dir: &Path) -> Result<usize, String> {
+    let manifest_path = snapshot_dir.join("snapshot-manifest.json");
+    let manifest_json = std::fs::read_to_string(&manifest_path)
+        .map_err(|e| format!("Failed to read snapshot manifest: {}", e))?;
+    let manifest: SnapshotManifest = serde_json::from_str(&manifest_json)
+        .map_err(|e| format!("Failed to parse snapshot manifest: {}", e))?;
+
+    if manifest.schema_version > SNAPSHOT_SCHEMA_VERSION {
+        return Err(format!(
+            "Snapshot schema version {} is newer than supported version {}",
+            manifest.schema_version, SNAPSHOT_SCHEMA_VERSION
+        ));
+    }
+
+    let target_data_dir = target_today_dir.join("data");
+    std::fs::create_dir_all(&target_data_dir)
+        .map_err(|e| format!("Failed to create {}: {}", target_data_dir.display(), e))?;
+
+    let mut restored = 0;
+    for entry in &manifest.files {
+        let source = snapshot_dir.join(&entry.relative_path);
+        let bytes = std::fs::read(&source)
+            .map_err(|e| format!("Failed to read {}: {}", source.display(), e))?;
+        let actual_hash = sha256_hex(&bytes);
+        if actual_hash != entry.sha256 {
+            return Err(format!(
+                "Checksum mismatch for {}: expected {}, got {}",
+                entry.relative_path, entry.sha256, actual_hash
+            ));
+        }
+
+        let dest = target_data_dir.join(&entry.relative_path);
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        std::fs::write(&dest, &bytes)
+            .map_err(|e| format!("Failed to write {}: {}", dest.display(), e))?;
+        restored += 1;
+    }
+
+    Ok(restored)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_capture_and_restore_snapshot_round_trip() {
+        let source = tempdir().expect("tempdir");
+        let today_dir = source.path();
+        let data_dir = today_dir.join("data");
+        std::fs::create_dir_all(data_dir.join("preps")).expect("create data dir");
+        std::fs::write(data_dir.join("schedule.json"), r#"{"date":"2026-07-30"}"#)
+            .expect("write schedule");
+        std::fs::write(
+            data_dir.join("preps").join("0900-acme.json"),
+            r#"{"meetingId":"m1"}"#,
+        )
+        .expect("write prep");
+
+        let snapshot_dir = capture_snapshot(today_dir, "2026-07-30T09:00:00Z", Some("default".to_string()))
+            .expect("capture snapshot");
+        assert!(snapshot_dir.join("snapshot-manifest.json").exists());
+        assert!(snapshot_dir.join("schedule.json").exists());
+        assert!(snapshot_dir.join("preps").join("0900-acme.json").exists());
+
+        let target = tempdir().expect("tempdir");
+        let restored = restore_snapshot(&snapshot_dir, target.path()).expect("restore snapshot");
+        assert_eq!(restored, 2);
+        assert_eq!(
+            std::fs::read_to_string(target.path().join("data").join("schedule.json")).unwrap(),
+            r#"{"date":"2026-07-30"}"#
+        );
+    }
+
+    #[test]
+    fn test_restore_snapshot_detects_tampering() {
+        let source = tempdir().expect("tempdir");
+        let today_dir = source.path();
+        let data_dir = today_dir.join("data");
+        std::fs::create_dir_all(&data_dir).expect("create data dir");
+        std::fs::write(data_dir.join("actions.json"), r#"{"date":"2026-07-30"}"#)
+            .expect("write actions");
+
+        let snapshot_dir =
+            capture_snapshot(today_dir, "2026-07-30T09:00:00Z", None).expect("capture snapshot");
+        std::fs::write(snapshot_dir.join("actions.json"), "tampered").expect("tamper");
+
+        let target = tempdir().expect("tempdir");
+        let result = restore_snapshot(&snapshot_dir, target.path());
+        assert!(result.is_err());
+    }
+}
@@ -466,6 +466,7 @@ pub fn create_or_update_config(
                 hygiene_scan_interval_hours: 4,
                 hygiene_ai_budget: 10,
                 hygiene_pre_meeting_hours: 12,
+                retention: crate::types::RetentionSettings::default(),
             }
         }
     };
@@ -57,6 +57,49 @@ pub struct Config {
     /// AI model configuration for tiered operations (I174).
     #[serde(default)]
     pub ai_models: AiModelConfig,
+    /// Transcript retention/archival policy (chunk199-2).
+    #[serde(default)]
+    pub retention: RetentionSettings,
+}
+
+/// Configurable transcript retention policy, surfaced through the
+/// `prune_transcripts`/`set_retention_settings` commands instead of the
+/// hardcoded `retention::RetentionConfig::default()` every call used to get.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetentionSettings {
+    /// Days to keep `_archive/<date>/*-transcript.md` files before pruning.
+    #[serde(default = "default_archive_retention_days")]
+    pub archive_retention_days: i64,
+    /// Days to keep `Accounts/<account>/Call-Transcripts/*-transcript.md`
+    /// files before pruning, absent a per-account override.
+    #[serde(default = "default_account_retention_days")]
+    pub account_retention_days: i64,
+    /// Per-account retention overrides, keyed by the account directory name.
+    #[serde(default)]
+    pub account_overrides: HashMap<String, i64>,
+    /// When set, pruned transcripts move here uncompressed instead of being
+    /// gzip-compressed in place.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cold_storage_dir: Option<String>,
+}
+
+impl Default for RetentionSettings {
+    fn default() -> Self {
+        Self {
+            archive_retention_days: default_archive_retention_days(),
+            account_retention_days: default_account_retention_days(),
+            account_overrides: HashMap::new(),
+            cold_storage_dir: None,
+        }
+    }
+}
+
+fn default_archive_retention_days() -> i64 {
+    30
+}
+fn default_account_retention_days() -> i64 {
+    180
 }
 
 /// Profile-specific configuration (CSM users)
@@ -710,6 +753,15 @@ pub struct Email {
     /// Email category from AI classification
     #[serde(skip_serializing_if = "Option::is_none")]
     pub email_type: Option<String>,
+    /// Commitments extracted by AI enrichment (I354)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub commitments: Vec<String>,
+    /// Questions extracted by AI enrichment (I354)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub questions: Vec<String>,
+    /// Sentiment from AI enrichment (I354)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sentiment: Option<String>,
 }
 
 /// Complete dashboard data payload
@@ -1359,10 +1411,40 @@ pub struct TranscriptResult {
     pub decisions: Vec<String>,
     #[serde(default)]
     pub actions: Vec<CapturedAction>,
+    /// Discussion highlights from transcript summarization (I31).
+    #[serde(default)]
+    pub discussion: Vec<String>,
+    /// Strategic TAM-perspective analysis from the transcript prompt.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub analysis: Option<String>,
+    /// Per-speaker talk-time breakdown, when the transcript carried speaker
+    /// labels (empty otherwise — e.g. a `plain`-format transcript with no
+    /// dialogue structure).
+    #[serde(default)]
+    pub speaker_stats: Vec<SpeakerTalkStats>,
+    /// Share of talk time (by word count) held by internal attendees, `None`
+    /// when diarization had nothing to work with. A rep dominating a customer
+    /// call (ratio close to 1.0) is a coachable risk signal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub talk_ratio: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 }
 
+/// One speaker's share of a transcript, from `processor::transcript::speakers`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpeakerTalkStats {
+    pub speaker: String,
+    /// Matched against the meeting's attendee list; `false` covers both the
+    /// customer side and the "unattributed" bucket for unlabeled turns.
+    pub is_internal: bool,
+    pub word_count: usize,
+    pub talk_seconds: f64,
+    /// Share of total transcript word count, in `[0.0, 1.0]`.
+    pub share: f64,
+}
+
 /// Outcomes for a meeting (query response)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
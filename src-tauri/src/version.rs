@@ -0,0 +1,41 @@
+//! Build-stamped provenance (chunk199-3).
+//!
+//! `build.rs` captures the git commit hash, build date, and release channel
+//! at compile time and exposes them via `env!(...)`. [`build_version`]
+//! renders them as `commit-hash date channel` (e.g. `a1b2c3d
+//! 2026-07-30T12:00:00Z dev`) for CLI `--version` output, and the same
+//! string is stamped into a generated transcript's frontmatter alongside
+//! [`ENRICHMENT_PROMPT_VERSION`] — together they record which build of the
+//! tool, and which version of the extraction prompt, produced a given
+//! SUMMARY/DECISIONS, so a stale or buggy enrichment can be traced back and
+//! regenerated.
+//!
+//! Falls back to `unknown`/`dev` when `.git` wasn't available at build time
+//! (e.g. a packaged build from a source tarball) — `build.rs` never fails
+//! the build over missing git metadata.
+
+/// Version of the enrichment prompt that produces SUMMARY/WINS/RISKS/
+/// DECISIONS/ACTIONS. Bump whenever the extraction prompt changes in a way
+/// that would make previously-generated output stale.
+pub const ENRICHMENT_PROMPT_VERSION: &str = "v1";
+
+/// `commit-hash date channel`, stamped at compile time by `build.rs`.
+pub fn build_version() -> String {
+    format!(
+        "{} {} {}",
+        env!("DAILYOS_GIT_COMMIT"),
+        env!("DAILYOS_BUILD_DATE"),
+        env!("DAILYOS_RELEASE_CHANNEL"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_version_has_three_space_separated_fields() {
+        let version = build_version();
+        assert_eq!(version.split(' ').count(), 3);
+    }
+}